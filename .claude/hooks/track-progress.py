@@ -147,6 +147,12 @@ def main():
         if status is not None:
             event_record["status"] = status
 
+        # Record the target path of file tools (used by 'ccmon show')
+        if hook_event == "PostToolUse":
+            file_path = hook_data.get("tool_input", {}).get("file_path")
+            if file_path:
+                event_record["file_path"] = file_path
+
         # Append to JSONL file with secure permissions (0o600)
         file_exists = progress_file.exists()
         with open(progress_file, "a") as f:
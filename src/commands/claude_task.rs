@@ -7,6 +7,8 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::SystemTime;
 
+use crate::output::Symbol;
+
 /// Claude Code task status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -24,13 +26,18 @@ pub enum TaskStatus {
 
 #[allow(dead_code)]
 impl TaskStatus {
-    /// Get emoji representation of status
+    /// Get emoji representation of status (ASCII when emoji are disabled)
     pub fn emoji(&self) -> &str {
+        self.symbol().as_str()
+    }
+
+    /// Get the output symbol for this status
+    pub fn symbol(&self) -> Symbol {
         match self {
-            TaskStatus::InProgress => "🔵",
-            TaskStatus::Stop => "🟡",
-            TaskStatus::SessionEnded => "⚫",
-            TaskStatus::Error => "🔴",
+            TaskStatus::InProgress => Symbol::TaskInProgress,
+            TaskStatus::Stop => Symbol::TaskStop,
+            TaskStatus::SessionEnded => Symbol::TaskSessionEnded,
+            TaskStatus::Error => Symbol::TaskError,
         }
     }
 
@@ -55,6 +62,33 @@ impl TaskStatus {
     }
 }
 
+/// Parse a retention period such as "30m", "12h", "3d" or "2w"
+pub fn parse_retention(value: &str) -> Result<chrono::Duration> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("Invalid retention period: '{}'", value))?;
+
+    let duration = match unit {
+        "m" => chrono::Duration::try_minutes(amount),
+        "h" => chrono::Duration::try_hours(amount),
+        "d" => chrono::Duration::try_days(amount),
+        "w" => chrono::Duration::try_weeks(amount),
+        _ => anyhow::bail!(
+            "Invalid retention unit in '{}' (use m, h, d or w, e.g. 3d)",
+            value
+        ),
+    };
+
+    // Amounts that fit in i64 can still overflow the duration range
+    duration.with_context(|| format!("Invalid retention period: '{}'", value))
+}
+
 /// Git project information for display
 #[derive(Debug, Clone, Default)]
 pub struct GitProjectInfo {
@@ -94,11 +128,52 @@ pub fn get_git_project_info(worktree_path: &str) -> GitProjectInfo {
     }
 }
 
-/// Extract repository name from git remote URL
-fn get_repo_name_from_git(path: &Path) -> Option<String> {
+/// Git summary of a single worktree for the UI focus view
+#[derive(Debug, Clone, Default)]
+pub struct WorktreeSummary {
+    /// Whether the path is inside a git work tree (all other fields are empty if not)
+    pub is_repository: bool,
+    /// Current branch (None when detached or not a git repository)
+    pub branch: Option<String>,
+    /// Uncommitted changes as reported by `git diff HEAD --shortstat` (None when clean)
+    pub diff_stat: Option<String>,
+    /// Most recent commits in `--oneline` format
+    pub recent_commits: Vec<String>,
+}
+
+/// Get branch, uncommitted changes and recent commits of a worktree
+pub fn get_worktree_summary(worktree_path: &str, commit_count: usize) -> WorktreeSummary {
+    let path = Path::new(worktree_path);
+
+    let is_repository = run_git(path, &["rev-parse", "--is-inside-work-tree"])
+        .is_some_and(|inside| inside == "true");
+    if !is_repository {
+        return WorktreeSummary::default();
+    }
+
+    let branch = run_git(path, &["branch", "--show-current"]).filter(|b| !b.is_empty());
+    let diff_stat = run_git(path, &["diff", "HEAD", "--shortstat"]).filter(|d| !d.is_empty());
+    let recent_commits = run_git(path, &["log", "--oneline", "-n", &commit_count.to_string()])
+        .map(|log| log.lines().map(|l| l.to_string()).collect())
+        .unwrap_or_default();
+
+    WorktreeSummary {
+        is_repository,
+        branch,
+        diff_stat,
+        recent_commits,
+    }
+}
+
+/// Run a git command in `path` and return its trimmed stdout on success
+fn run_git(path: &Path, args: &[&str]) -> Option<String> {
+    // GIT_DIR/GIT_WORK_TREE (e.g. set by direnv) would override current_dir
+    // and report the same repository for every task
     let output = Command::new("git")
-        .args(["remote", "get-url", "origin"])
+        .args(args)
         .current_dir(path)
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
         .output()
         .ok()?;
 
@@ -106,8 +181,13 @@ fn get_repo_name_from_git(path: &Path) -> Option<String> {
         return None;
     }
 
-    let url = String::from_utf8_lossy(&output.stdout);
-    parse_repo_name_from_url(url.trim())
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Extract repository name from git remote URL
+fn get_repo_name_from_git(path: &Path) -> Option<String> {
+    let url = run_git(path, &["remote", "get-url", "origin"])?;
+    parse_repo_name_from_url(&url)
 }
 
 /// Parse repository name from git URL
@@ -145,6 +225,9 @@ pub struct TaskEvent {
     pub message: String,
     /// Working directory where event occurred
     pub cwd: String,
+    /// Target file of file tools (Write, Edit, Read), if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
 }
 
 /// Aggregated task information for a Claude Code session
@@ -274,6 +357,21 @@ impl ClaudeTask {
         usage
     }
 
+    /// Get files touched by tools, in order of first use
+    pub fn files_touched(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = Vec::new();
+
+        for event in &self.events {
+            if let Some(path) = event.file_path.as_deref() {
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+        }
+
+        files
+    }
+
     /// Check if task has actually started (not just SessionStart)
     pub fn has_started(&self) -> bool {
         // Task has started if there are multiple events or single non-SessionStart event
@@ -283,6 +381,11 @@ impl ClaudeTask {
                 .first()
                 .is_some_and(|e| e.event != "SessionStart")
     }
+
+    /// Check if task counts as active (started and not ended)
+    pub fn is_active(&self) -> bool {
+        self.status != TaskStatus::SessionEnded && self.has_started()
+    }
 }
 
 /// Manager for multiple Claude Code task sessions
@@ -293,6 +396,12 @@ pub struct TaskManager {
     tasks: HashMap<String, ClaudeTask>,
     /// File modification times for caching
     file_mtimes: HashMap<PathBuf, SystemTime>,
+    /// Explicit session_id -> worktree path bindings (override the event cwd)
+    bindings: HashMap<String, String>,
+    /// How long ended/errored sessions stay visible (None = forever)
+    retention: Option<chrono::Duration>,
+    /// Non-fatal problems found while loading (unreadable files, invalid lines)
+    warnings: Vec<String>,
 }
 
 #[allow(dead_code)]
@@ -302,6 +411,35 @@ impl TaskManager {
         Self {
             tasks: HashMap::new(),
             file_mtimes: HashMap::new(),
+            bindings: HashMap::new(),
+            retention: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Take the warnings collected since the last call
+    ///
+    /// Loading never prints, so the caller decides where warnings go
+    /// (stderr for CLI commands, toasts in the TUI).
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Hide ended/errored sessions whose last update is older than `retention`
+    pub fn set_retention(&mut self, retention: Option<chrono::Duration>) {
+        self.retention = retention;
+    }
+
+    /// Check whether a task should be shown under the retention policy
+    /// Running and waiting sessions are always visible
+    fn is_retained(&self, task: &ClaudeTask, now: DateTime<Utc>) -> bool {
+        match self.retention {
+            Some(retention)
+                if matches!(task.status, TaskStatus::SessionEnded | TaskStatus::Error) =>
+            {
+                now - task.last_update <= retention
+            }
+            _ => true,
         }
     }
 
@@ -314,6 +452,7 @@ impl TaskManager {
         }
 
         let mut manager = Self::new();
+        manager.reload_bindings();
 
         for entry in fs::read_dir(&progress_dir)
             .with_context(|| format!("Failed to read directory: {}", progress_dir.display()))?
@@ -334,7 +473,9 @@ impl TaskManager {
             }
 
             if let Err(e) = manager.load_session_file(&path) {
-                eprintln!("Warning: Failed to load {}: {}", path.display(), e);
+                manager
+                    .warnings
+                    .push(format!("Failed to load {}: {}", path.display(), e));
             }
         }
 
@@ -362,24 +503,24 @@ impl TaskManager {
                 }
                 Err(e) => {
                     parse_errors += 1;
-                    eprintln!(
-                        "⚠️  Warning: Skipping invalid line in {}:{}: {}",
+                    self.warnings.push(format!(
+                        "Skipping invalid line in {}:{}: {}",
                         path.display(),
                         line_num + 1,
                         e
-                    );
+                    ));
                     // Continue processing remaining lines
                 }
             }
         }
 
         if parse_errors > 0 {
-            eprintln!(
-                "⚠️  Session file {} had {} parse errors ({} events loaded successfully)",
+            self.warnings.push(format!(
+                "Session file {} had {} parse errors ({} events loaded successfully)",
                 path.display(),
                 parse_errors,
                 valid_events
-            );
+            ));
         }
 
         Ok(())
@@ -390,16 +531,148 @@ impl TaskManager {
         let session_id = event.session_id.clone();
 
         self.tasks
-            .entry(session_id)
+            .entry(session_id.clone())
             .and_modify(|task| task.add_event(event.clone()))
             .or_insert_with(|| ClaudeTask::new(event));
+
+        // An explicit binding always wins over the cwd reported by the event
+        if let (Some(task), Some(bound)) = (
+            self.tasks.get_mut(&session_id),
+            self.bindings.get(&session_id),
+        ) {
+            task.worktree_path = bound.clone();
+        }
+    }
+
+    /// Find a task by session ID or unique session ID prefix
+    pub fn find_task(&self, session_prefix: &str) -> Result<&ClaudeTask> {
+        if let Some(task) = self.tasks.get(session_prefix) {
+            return Ok(task);
+        }
+
+        let matches: Vec<_> = self
+            .all_tasks()
+            .into_iter()
+            .filter(|t| t.session_id.starts_with(session_prefix))
+            .collect();
+
+        match matches.as_slice() {
+            [task] => Ok(task),
+            [] => anyhow::bail!(
+                "No Claude Code session found matching: {}\n\n\
+                 Check the session ID shown in 'ccmon ui'.",
+                session_prefix
+            ),
+            _ => anyhow::bail!(
+                "Session ID prefix '{}' is ambiguous ({} sessions match)\n\n\
+                 Use a longer prefix.",
+                session_prefix,
+                matches.len()
+            ),
+        }
+    }
+
+    /// Get the path of the session binding file
+    pub fn get_bindings_path() -> PathBuf {
+        Self::get_progress_dir().join("bindings.json")
+    }
+
+    /// Read session bindings from disk (empty if the file does not exist)
+    pub fn load_bindings() -> Result<HashMap<String, String>> {
+        let path = Self::get_bindings_path();
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse bindings file: {}", path.display()))
+    }
+
+    /// Bind a session to a worktree path, or remove the binding when `worktree_path` is None
+    pub fn save_binding(session_id: &str, worktree_path: Option<&Path>) -> Result<()> {
+        let mut bindings = Self::load_bindings()?;
+        match worktree_path {
+            Some(path) => {
+                bindings.insert(session_id.to_string(), path.display().to_string());
+            }
+            None => {
+                bindings.remove(session_id);
+            }
+        }
+
+        Self::write_bindings(&bindings)
+    }
+
+    /// Drop the bindings of deleted sessions so they are not applied to a reused session ID
+    pub fn remove_bindings(session_ids: &[String]) -> Result<()> {
+        let mut bindings = Self::load_bindings()?;
+        let before = bindings.len();
+        for session_id in session_ids {
+            bindings.remove(session_id);
+        }
+
+        if bindings.len() == before {
+            return Ok(());
+        }
+        Self::write_bindings(&bindings)
+    }
+
+    /// Delete the binding file (used when all sessions are cleared)
+    pub fn clear_bindings() -> Result<()> {
+        let path = Self::get_bindings_path();
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to delete file: {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Write the binding file, creating the progress directory if needed
+    fn write_bindings(bindings: &HashMap<String, String>) -> Result<()> {
+        let path = Self::get_bindings_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(bindings)?;
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write bindings file: {}", path.display()))
+    }
+
+    /// Reload bindings from disk and apply them to already loaded tasks
+    fn reload_bindings(&mut self) {
+        match Self::load_bindings() {
+            Ok(bindings) => self.set_bindings(bindings),
+            Err(e) => self
+                .warnings
+                .push(format!("Failed to load session bindings: {}", e)),
+        }
+    }
+
+    /// Replace bindings and re-apply them to loaded tasks
+    fn set_bindings(&mut self, bindings: HashMap<String, String>) {
+        self.bindings = bindings;
+        for (session_id, task) in self.tasks.iter_mut() {
+            if let Some(bound) = self.bindings.get(session_id) {
+                task.worktree_path = bound.clone();
+            } else if let Some(event) = task.events.last() {
+                task.worktree_path = event.cwd.clone();
+            }
+        }
     }
 
     /// Get all tasks
     pub fn all_tasks(&self) -> Vec<&ClaudeTask> {
-        let mut tasks: Vec<_> = self.tasks.values().collect();
+        let now = Utc::now();
+        let mut tasks: Vec<_> = self
+            .tasks
+            .values()
+            .filter(|t| self.is_retained(t, now))
+            .collect();
         // Sort by last update, most recent first
-        tasks.sort_by(|a, b| b.last_update.cmp(&a.last_update));
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.last_update));
         tasks
     }
 
@@ -407,7 +680,7 @@ impl TaskManager {
     pub fn active_tasks(&self) -> Vec<&ClaudeTask> {
         self.all_tasks()
             .into_iter()
-            .filter(|t| t.status != TaskStatus::SessionEnded && t.has_started())
+            .filter(|t| t.is_active())
             .collect()
     }
 
@@ -419,13 +692,23 @@ impl TaskManager {
             .collect()
     }
 
+    /// Get tasks whose worktree directory no longer exists
+    /// (e.g. the worktree was removed after the session ended)
+    pub fn orphaned_tasks(&self) -> Vec<&ClaudeTask> {
+        self.all_tasks()
+            .into_iter()
+            .filter(|t| !Path::new(&t.worktree_path).exists())
+            .collect()
+    }
+
     /// Get the latest task for each unique worktree path
     /// Returns tasks grouped by worktree, keeping only the most recent session for each
     pub fn latest_tasks_by_worktree(&self) -> Vec<&ClaudeTask> {
         // Group tasks by worktree_path, keeping only the most recent
         let mut worktree_latest: HashMap<&str, &ClaudeTask> = HashMap::new();
+        let now = Utc::now();
 
-        for task in self.tasks.values() {
+        for task in self.tasks.values().filter(|t| self.is_retained(t, now)) {
             let worktree = task.worktree_path.as_str();
 
             match worktree_latest.get(worktree) {
@@ -442,7 +725,7 @@ impl TaskManager {
 
         // Collect and sort by last_update (most recent first)
         let mut tasks: Vec<_> = worktree_latest.into_values().collect();
-        tasks.sort_by(|a, b| b.last_update.cmp(&a.last_update));
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.last_update));
         tasks
     }
 
@@ -460,6 +743,9 @@ impl TaskManager {
             return Ok(());
         }
 
+        // Bindings may have been changed by 'ccmon bind' in another terminal
+        self.reload_bindings();
+
         for entry in fs::read_dir(&progress_dir)
             .with_context(|| format!("Failed to read directory: {}", progress_dir.display()))?
         {
@@ -493,7 +779,8 @@ impl TaskManager {
                 }
 
                 if let Err(e) = self.load_session_file(&path) {
-                    eprintln!("Warning: Failed to reload {}: {}", path.display(), e);
+                    self.warnings
+                        .push(format!("Failed to reload {}: {}", path.display(), e));
                 }
 
                 self.file_mtimes.insert(path, current_mtime);
@@ -560,6 +847,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Test".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         };
 
         let task = ClaudeTask::new(event);
@@ -587,6 +875,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Test".to_string(),
             cwd: "/home/user/feature".to_string(),
+            file_path: None,
         };
 
         let task = ClaudeTask::new(event);
@@ -635,6 +924,12 @@ mod tests {
         assert_eq!(task.events.len(), 3);
         assert_eq!(task.status, TaskStatus::SessionEnded);
 
+        // Skipped lines are collected (two lines + file summary), not printed
+        let warnings = manager.take_warnings();
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("broken.jsonl:2"));
+        assert!(manager.take_warnings().is_empty());
+
         Ok(())
     }
 
@@ -655,6 +950,7 @@ mod tests {
                 status: Some(TaskStatus::InProgress),
                 message: format!("Event {}", i),
                 cwd: "/tmp".to_string(),
+                file_path: None,
             });
         }
 
@@ -669,6 +965,43 @@ mod tests {
         assert_eq!(usage.get("Bash"), Some(&1));
     }
 
+    #[test]
+    fn test_task_files_touched() {
+        let mut task = ClaudeTask::new(TaskEvent {
+            timestamp: Utc::now(),
+            session_id: "test".to_string(),
+            event: "SessionStart".to_string(),
+            tool: None,
+            status: None,
+            message: "Session started".to_string(),
+            cwd: "/tmp".to_string(),
+            file_path: None,
+        });
+
+        for (tool, path) in [
+            ("Edit", Some("/tmp/src/main.rs")),
+            ("Bash", None),
+            ("Read", Some("/tmp/Cargo.toml")),
+            ("Edit", Some("/tmp/src/main.rs")),
+        ] {
+            task.add_event(TaskEvent {
+                timestamp: Utc::now(),
+                session_id: "test".to_string(),
+                event: "PostToolUse".to_string(),
+                tool: Some(tool.to_string()),
+                status: Some(TaskStatus::InProgress),
+                message: format!("Used tool: {}", tool),
+                cwd: "/tmp".to_string(),
+                file_path: path.map(|p| p.to_string()),
+            });
+        }
+
+        assert_eq!(
+            task.files_touched(),
+            vec!["/tmp/src/main.rs", "/tmp/Cargo.toml"]
+        );
+    }
+
     #[test]
     fn test_task_manager_refresh_only_changed_files() -> Result<()> {
         // This test verifies that refresh() only reloads changed files
@@ -686,6 +1019,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Test".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         };
         manager.add_event(event);
 
@@ -735,6 +1069,7 @@ mod tests {
                 status: Some(*status),
                 message: "Test".to_string(),
                 cwd: "/tmp".to_string(),
+                file_path: None,
             };
             manager.add_event(event);
         }
@@ -757,6 +1092,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         };
 
         let event_without_status = TaskEvent {
@@ -767,6 +1103,7 @@ mod tests {
             status: None,
             message: "Unknown event".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         };
 
         let mut task = ClaudeTask::new(event_with_status);
@@ -823,6 +1160,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         };
         let task = ClaudeTask::new(session_start_only);
         assert!(!task.has_started());
@@ -836,6 +1174,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         task.add_event(TaskEvent {
             timestamp: Utc::now(),
@@ -845,6 +1184,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Read file".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         assert!(task.has_started());
     }
@@ -862,6 +1202,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
 
         // Add task with SessionStart + PostToolUse
@@ -873,6 +1214,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         manager.add_event(TaskEvent {
             timestamp: Utc::now(),
@@ -882,6 +1224,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Read file".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
 
         // Only the started task should be active
@@ -901,6 +1244,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         assert!(!task.has_started());
 
@@ -912,6 +1256,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Processing user prompt".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         assert!(task.has_started());
         assert_eq!(task.status, TaskStatus::InProgress);
@@ -930,6 +1275,7 @@ mod tests {
             status: None,
             message: "Session started".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
         manager.add_event(TaskEvent {
             timestamp: Utc::now(),
@@ -939,6 +1285,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Processing user prompt".to_string(),
             cwd: "/tmp".to_string(),
+            file_path: None,
         });
 
         // Task should be active after UserPromptSubmit
@@ -961,6 +1308,7 @@ mod tests {
             status: Some(TaskStatus::SessionEnded),
             message: "Old session".to_string(),
             cwd: "/home/user/project".to_string(),
+            file_path: None,
         });
 
         // Add newer session for same worktree
@@ -972,6 +1320,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "New session".to_string(),
             cwd: "/home/user/project".to_string(),
+            file_path: None,
         });
 
         // Add session for different worktree
@@ -983,6 +1332,7 @@ mod tests {
             status: Some(TaskStatus::InProgress),
             message: "Other project".to_string(),
             cwd: "/home/user/other-project".to_string(),
+            file_path: None,
         });
 
         let latest = manager.latest_tasks_by_worktree();
@@ -999,6 +1349,151 @@ mod tests {
         assert_eq!(project_task.status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_orphaned_tasks() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut manager = TaskManager::new();
+
+        manager.add_event(TaskEvent {
+            timestamp: Utc::now(),
+            session_id: "existing".to_string(),
+            event: "Stop".to_string(),
+            tool: None,
+            status: Some(TaskStatus::Stop),
+            message: "Waiting".to_string(),
+            cwd: temp_dir.path().display().to_string(),
+            file_path: None,
+        });
+        manager.add_event(TaskEvent {
+            timestamp: Utc::now(),
+            session_id: "removed".to_string(),
+            event: "Stop".to_string(),
+            tool: None,
+            status: Some(TaskStatus::Stop),
+            message: "Waiting".to_string(),
+            cwd: temp_dir
+                .path()
+                .join("removed-worktree")
+                .display()
+                .to_string(),
+            file_path: None,
+        });
+
+        let orphaned = manager.orphaned_tasks();
+        assert_eq!(orphaned.len(), 1);
+        assert_eq!(orphaned[0].session_id, "removed");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_binding_overrides_event_cwd() {
+        let mut manager = TaskManager::new();
+        manager.set_bindings(HashMap::from([(
+            "bound".to_string(),
+            "/home/user/feature".to_string(),
+        )]));
+
+        manager.add_event(TaskEvent {
+            timestamp: Utc::now(),
+            session_id: "bound".to_string(),
+            event: "PostToolUse".to_string(),
+            tool: Some("Bash".to_string()),
+            status: Some(TaskStatus::InProgress),
+            message: "Executed: cargo test".to_string(),
+            cwd: "/tmp/scratch".to_string(),
+            file_path: None,
+        });
+
+        let task = manager.get_task("bound").expect("Task should exist");
+        assert_eq!(task.worktree_path, "/home/user/feature");
+
+        // Removing the binding falls back to the event cwd
+        manager.set_bindings(HashMap::new());
+        let task = manager.get_task("bound").expect("Task should exist");
+        assert_eq!(task.worktree_path, "/tmp/scratch");
+    }
+
+    #[test]
+    fn test_find_task_by_prefix() {
+        let mut manager = TaskManager::new();
+        for session_id in ["abc123", "abd456"] {
+            manager.add_event(TaskEvent {
+                timestamp: Utc::now(),
+                session_id: session_id.to_string(),
+                event: "Stop".to_string(),
+                tool: None,
+                status: Some(TaskStatus::Stop),
+                message: "Waiting".to_string(),
+                cwd: "/tmp".to_string(),
+                file_path: None,
+            });
+        }
+
+        assert_eq!(
+            manager.find_task("abc").map(|t| t.session_id.as_str()).ok(),
+            Some("abc123")
+        );
+        // Ambiguous and unknown prefixes are errors
+        assert!(manager.find_task("ab").is_err());
+        assert!(manager.find_task("zzz").is_err());
+    }
+
+    #[test]
+    fn test_parse_retention() {
+        assert_eq!(
+            parse_retention("30m").ok(),
+            Some(chrono::Duration::minutes(30))
+        );
+        assert_eq!(
+            parse_retention("12h").ok(),
+            Some(chrono::Duration::hours(12))
+        );
+        assert_eq!(parse_retention("3d").ok(), Some(chrono::Duration::days(3)));
+        assert_eq!(parse_retention("2w").ok(), Some(chrono::Duration::weeks(2)));
+        assert!(parse_retention("3").is_err());
+        assert!(parse_retention("d").is_err());
+        assert!(parse_retention("3y").is_err());
+        assert!(parse_retention("9999999999999999w").is_err());
+        assert!(parse_retention("9223372036854775807m").is_err());
+    }
+
+    #[test]
+    fn test_retention_hides_old_finished_tasks() {
+        let mut manager = TaskManager::new();
+        let old = Utc::now() - chrono::Duration::days(5);
+
+        for (session_id, status, timestamp) in [
+            ("old_ended", TaskStatus::SessionEnded, old),
+            ("old_error", TaskStatus::Error, old),
+            ("old_waiting", TaskStatus::Stop, old),
+            ("recent_ended", TaskStatus::SessionEnded, Utc::now()),
+        ] {
+            manager.add_event(TaskEvent {
+                timestamp,
+                session_id: session_id.to_string(),
+                event: "Stop".to_string(),
+                tool: None,
+                status: Some(status),
+                message: "Test".to_string(),
+                cwd: format!("/tmp/{}", session_id),
+                file_path: None,
+            });
+        }
+
+        assert_eq!(manager.all_tasks().len(), 4);
+
+        manager.set_retention(Some(chrono::Duration::days(3)));
+        let mut visible: Vec<_> = manager
+            .latest_tasks_by_worktree()
+            .iter()
+            .map(|t| t.session_id.as_str())
+            .collect();
+        visible.sort();
+        assert_eq!(visible, vec!["old_waiting", "recent_ended"]);
+        assert_eq!(manager.all_tasks().len(), 2);
+    }
+
     #[test]
     fn test_parse_repo_name_https() {
         assert_eq!(
@@ -1035,6 +1530,38 @@ mod tests {
         assert_eq!(super::parse_repo_name_from_url(".git"), None);
     }
 
+    #[test]
+    fn test_worktree_summary() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(path)
+                .env_remove("GIT_DIR")
+                .env_remove("GIT_WORK_TREE")
+                .output()
+                .is_ok_and(|o| o.status.success())
+        };
+        assert!(git(&["init", "-q", "-b", "main"]), "git init failed");
+        fs::write(path.join("a.txt"), "one\n")?;
+        assert!(git(&["add", "a.txt"]));
+        assert!(git(&["commit", "-q", "-m", "First commit"]));
+        fs::write(path.join("a.txt"), "one\ntwo\n")?;
+
+        let summary = get_worktree_summary(&path.display().to_string(), 3);
+        assert!(summary.is_repository);
+        assert_eq!(summary.branch.as_deref(), Some("main"));
+        assert_eq!(summary.recent_commits.len(), 1);
+        assert!(summary.recent_commits[0].ends_with("First commit"));
+        assert!(summary
+            .diff_stat
+            .is_some_and(|d| d.contains("1 file changed")));
+
+        Ok(())
+    }
+
     #[test]
     fn test_git_project_info_display() {
         let info = GitProjectInfo {
@@ -1,22 +1,29 @@
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame, Terminal,
 };
 use std::collections::HashMap;
 use std::io;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
-use crate::commands::claude_task::{get_git_project_info, GitProjectInfo, TaskManager, TaskStatus};
+use crate::commands::claude_task::{
+    get_git_project_info, get_worktree_summary, ClaudeTask, GitProjectInfo, TaskManager,
+    TaskStatus, WorktreeSummary,
+};
 
 /// Display mode based on terminal height
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -51,6 +58,292 @@ impl DisplayMode {
     }
 }
 
+/// Which screen the TUI is showing
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ViewMode {
+    /// Task list with details panel
+    List,
+    /// Tasks laid out in columns by status
+    Kanban,
+}
+
+impl ViewMode {
+    /// Switch to the other view
+    fn toggle(self) -> Self {
+        match self {
+            ViewMode::List => ViewMode::Kanban,
+            ViewMode::Kanban => ViewMode::List,
+        }
+    }
+}
+
+/// Kanban columns in display order, with the statuses each one collects
+const KANBAN_COLUMNS: [(&str, TaskStatus); 4] = [
+    ("In Progress", TaskStatus::InProgress),
+    ("Waiting", TaskStatus::Stop),
+    ("Completed", TaskStatus::SessionEnded),
+    ("Error", TaskStatus::Error),
+];
+
+/// Visible task indices in each Kanban column (in `KANBAN_COLUMNS` order), top to bottom
+fn kanban_columns(tasks: &[&ClaudeTask]) -> Vec<Vec<usize>> {
+    KANBAN_COLUMNS
+        .iter()
+        .map(|(_, status)| {
+            tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, task)| task.status == *status)
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .collect()
+}
+
+/// Direction of a Kanban selection move
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KanbanMove {
+    Up,
+    Down,
+    PreviousColumn,
+    NextColumn,
+}
+
+/// Task index selected after moving on the Kanban board
+///
+/// Up/Down wrap within the current column. Column moves skip empty columns,
+/// stop at the board edges and keep the row where possible. If the current
+/// selection is not on the board, the first card is selected.
+fn kanban_move(columns: &[Vec<usize>], selected: usize, step: KanbanMove) -> Option<usize> {
+    let current = columns.iter().enumerate().find_map(|(column, cards)| {
+        cards
+            .iter()
+            .position(|&index| index == selected)
+            .map(|row| (column, row))
+    });
+    let Some((column, row)) = current else {
+        return columns.iter().find_map(|cards| cards.first().copied());
+    };
+
+    let cards = &columns[column];
+    let target = match step {
+        KanbanMove::Down => Some((column, (row + 1) % cards.len())),
+        KanbanMove::Up => Some((column, (row + cards.len() - 1) % cards.len())),
+        KanbanMove::NextColumn => (column + 1..columns.len())
+            .find(|&c| !columns[c].is_empty())
+            .map(|c| (c, row.min(columns[c].len() - 1))),
+        KanbanMove::PreviousColumn => (0..column)
+            .rev()
+            .find(|&c| !columns[c].is_empty())
+            .map(|c| (c, row.min(columns[c].len() - 1))),
+    };
+
+    target.map(|(c, r)| columns[c][r])
+}
+
+/// Number of recent commits shown in focus mode
+const FOCUS_COMMIT_COUNT: usize = 3;
+
+/// Height of the focus panel: branch/changes line + commits + border
+const FOCUS_PANEL_HEIGHT: u16 = FOCUS_COMMIT_COUNT as u16 + 3;
+
+/// How often the focus panel re-runs git (task data refreshes every second)
+const FOCUS_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Actions that can be triggered from keys or the command palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    NextTask,
+    PreviousTask,
+    Refresh,
+    ToggleView,
+    NextRepository,
+    PreviousRepository,
+    NextColumn,
+    PreviousColumn,
+    OpenPalette,
+    Quit,
+}
+
+/// Palette entries: action, label searched by the fuzzy filter, and its direct key
+const PALETTE_ACTIONS: [(Action, &str, &str); 9] = [
+    (Action::NextTask, "Select next task", "j"),
+    (Action::PreviousTask, "Select previous task", "k"),
+    (Action::NextColumn, "Select card in next Kanban column", "l"),
+    (
+        Action::PreviousColumn,
+        "Select card in previous Kanban column",
+        "h",
+    ),
+    (Action::Refresh, "Refresh tasks", "r"),
+    (Action::ToggleView, "Switch list / Kanban view", "Tab"),
+    (Action::NextRepository, "Show next repository", "]"),
+    (Action::PreviousRepository, "Show previous repository", "["),
+    (Action::Quit, "Quit", "q"),
+];
+
+/// Footer hints; each one can also be clicked to run its action
+const FOOTER_HINTS: [(&str, Action); 4] = [
+    (": for commands", Action::OpenPalette),
+    ("Tab to switch view", Action::ToggleView),
+    ("r to refresh", Action::Refresh),
+    ("q to quit", Action::Quit),
+];
+
+/// Lines per Kanban card (project, duration, last message, spacer)
+const KANBAN_CARD_HEIGHT: u16 = 4;
+
+/// Screen regions recorded during the last draw, used to resolve mouse clicks
+#[derive(Debug, Default)]
+struct ClickTargets {
+    /// Task list area and the height of each list item
+    list: Option<(Rect, u16)>,
+    /// Kanban column areas with the visible task index of each card (top to bottom)
+    /// and the index of the first card on screen
+    kanban: Vec<(Rect, Vec<usize>, usize)>,
+    /// Footer hint regions and the action each one triggers
+    footer: Vec<(Rect, Action)>,
+}
+
+/// Click regions of the footer hints, laid out after a prefix of `prefix_width` cells
+///
+/// The footer text is cut off at the inner area, so regions are clipped to it and
+/// hints starting past its right edge get no region at all.
+fn footer_hint_targets(inner: Rect, prefix_width: u16) -> Vec<(Rect, Action)> {
+    let mut targets = Vec::new();
+    let mut x = inner.x.saturating_add(prefix_width);
+    for (index, (hint, action)) in FOOTER_HINTS.iter().enumerate() {
+        if index > 0 {
+            x = x.saturating_add(2); // ", "
+        }
+        let width = hint.chars().count() as u16;
+        let target = Rect::new(x, inner.y, width, 1).intersection(inner);
+        if !target.is_empty() {
+            targets.push((target, *action));
+        }
+        x = x.saturating_add(width);
+    }
+    targets
+}
+
+/// Whether a screen cell lies inside a rectangle
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+/// Index of the bordered list entry at `row`, given the first visible index (`offset`)
+/// and the height of each entry. Returns None for clicks on the border.
+fn list_index_at(area: Rect, item_height: u16, offset: usize, row: u16) -> Option<usize> {
+    let inner_top = area.y + 1;
+    let inner_bottom = area.bottom().saturating_sub(1);
+    if row < inner_top || row >= inner_bottom || item_height == 0 {
+        return None;
+    }
+    Some(offset + ((row - inner_top) / item_height) as usize)
+}
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Maximum number of toasts shown at once (oldest are dropped first)
+const MAX_TOASTS: usize = 3;
+
+/// Severity of a toast notification
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ToastLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// Border and text color for the level
+    fn color(self) -> Color {
+        match self {
+            ToastLevel::Info => Color::Blue,
+            ToastLevel::Warning => Color::Yellow,
+            ToastLevel::Error => Color::Red,
+        }
+    }
+}
+
+/// Transient message shown in the bottom-right corner of the TUI
+#[derive(Debug)]
+struct Toast {
+    level: ToastLevel,
+    message: String,
+    shown_at: Instant,
+}
+
+/// Add a toast, folding repeats of the newest message into it
+/// (auto-refresh would otherwise stack the same failure every second)
+fn push_toast(toasts: &mut Vec<Toast>, level: ToastLevel, message: String) {
+    let now = Instant::now();
+    if let Some(last) = toasts.last_mut() {
+        if last.level == level && last.message == message {
+            last.shown_at = now;
+            return;
+        }
+    }
+
+    toasts.push(Toast {
+        level,
+        message,
+        shown_at: now,
+    });
+    if toasts.len() > MAX_TOASTS {
+        toasts.remove(0);
+    }
+}
+
+/// Command palette state (opened with `:`)
+#[derive(Debug, Default)]
+struct Palette {
+    query: String,
+    selected: usize,
+    /// Repository switching does nothing in focus mode, so those entries are hidden
+    focus_mode: bool,
+}
+
+impl Palette {
+    /// Palette entries matching the query, best match first
+    fn matches(&self) -> Vec<(Action, &'static str, &'static str)> {
+        let mut scored: Vec<_> = PALETTE_ACTIONS
+            .iter()
+            .filter(|(action, _, _)| {
+                !(self.focus_mode
+                    && matches!(action, Action::NextRepository | Action::PreviousRepository))
+            })
+            .filter_map(|&entry| fuzzy_score(&self.query, entry.1).map(|score| (score, entry)))
+            .collect();
+        // Stable sort keeps the declaration order for equal scores
+        scored.sort_by_key(|(score, _)| *score);
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+}
+
+/// Score `candidate` against a fuzzy `query` (lower is better)
+///
+/// Every query character must appear in order (case-insensitive); the score
+/// is the number of skipped characters, so contiguous matches rank first.
+/// Returns None if the candidate does not match.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut position = 0;
+    let mut skipped = 0;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let offset = candidate[position..].iter().position(|&c| c == q)?;
+        // Leading characters before the first match are not penalized
+        if position > 0 {
+            skipped += offset;
+        }
+        position += offset + 1;
+    }
+
+    Some(skipped)
+}
+
 /// Map task status to display color
 fn status_color(status: TaskStatus) -> Color {
     match status {
@@ -67,43 +360,284 @@ struct App {
     selected_index: usize,
     list_state: ListState,
     should_quit: bool,
+    view_mode: ViewMode,
     last_refresh: Instant,
     auto_refresh_interval: Duration,
     /// Cache for git project info (worktree_path -> info)
     git_info_cache: HashMap<String, GitProjectInfo>,
+    /// Repository shown with [ / ] switching (None = all repositories)
+    repo_filter: Option<String>,
+    /// Worktree path in focus mode (`ccmon ui <path>`)
+    focus: Option<String>,
+    /// Sessions of the focused worktree, most recent first
+    /// (matching paths canonicalizes them, so this is computed once per refresh)
+    focus_session_ids: Vec<String>,
+    /// Git summary of the focused worktree
+    focus_summary: WorktreeSummary,
+    /// When the focus summary was last collected (None = collect on the next refresh)
+    focus_refreshed: Option<Instant>,
+    /// Command palette, when open
+    palette: Option<Palette>,
+    /// Clickable regions from the last draw
+    click_targets: ClickTargets,
+    /// Notifications shown over the UI, oldest first
+    toasts: Vec<Toast>,
 }
 
 impl App {
-    fn new() -> Result<Self> {
+    fn new(retention: Option<chrono::Duration>, focus: Option<String>) -> Result<Self> {
         // Load Claude Code task progress
-        let task_manager = match TaskManager::load() {
+        // The UI runs on the alternate screen, so load problems become toasts
+        let mut toasts = Vec::new();
+        let mut task_manager = match TaskManager::load() {
             Ok(tm) => tm,
             Err(e) => {
-                eprintln!("Warning: Failed to load Claude Code task progress: {}", e);
-                eprintln!("    Continuing with empty task manager...");
+                push_toast(
+                    &mut toasts,
+                    ToastLevel::Warning,
+                    format!(
+                        "Failed to load Claude Code task progress: {} (continuing with no tasks)",
+                        e
+                    ),
+                );
                 TaskManager::default()
             }
         };
-
-        let mut list_state = ListState::default();
-        let tasks = task_manager.latest_tasks_by_worktree();
-        if !tasks.is_empty() {
-            list_state.select(Some(0));
+        task_manager.set_retention(retention);
+        for warning in task_manager.take_warnings() {
+            push_toast(&mut toasts, ToastLevel::Warning, warning);
         }
 
-        Ok(Self {
+        let focus_summary = focus
+            .as_deref()
+            .map(|path| get_worktree_summary(path, FOCUS_COMMIT_COUNT))
+            .unwrap_or_default();
+
+        let mut app = Self {
             task_manager,
             selected_index: 0,
-            list_state,
+            list_state: ListState::default(),
             should_quit: false,
+            view_mode: ViewMode::List,
             last_refresh: Instant::now(),
             auto_refresh_interval: Duration::from_secs(1), // Auto-refresh every 1 second
             git_info_cache: HashMap::new(),
-        })
+            repo_filter: None,
+            focus,
+            focus_session_ids: Vec::new(),
+            focus_summary,
+            focus_refreshed: Some(Instant::now()),
+            palette: None,
+            click_targets: ClickTargets::default(),
+            toasts,
+        };
+
+        if app.focus.is_none() {
+            app.cache_all_git_info();
+        }
+        app.update_focus_sessions();
+        if !app.visible_tasks().is_empty() {
+            app.list_state.select(Some(0));
+        }
+
+        Ok(app)
+    }
+
+    /// Run an action triggered by a key or the command palette
+    fn perform(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::NextTask => self.next(),
+            Action::PreviousTask => self.previous(),
+            Action::NextColumn => self.move_kanban(KanbanMove::NextColumn),
+            Action::PreviousColumn => self.move_kanban(KanbanMove::PreviousColumn),
+            Action::Refresh => {
+                // Manual refresh also updates the focus panel immediately
+                self.focus_refreshed = None;
+                if self.refresh()? {
+                    let count = self.visible_tasks().len();
+                    self.notify(ToastLevel::Info, format!("Refreshed {} task(s)", count));
+                }
+            }
+            Action::ToggleView => self.view_mode = self.view_mode.toggle(),
+            Action::NextRepository => self.cycle_repo(true),
+            Action::PreviousRepository => self.cycle_repo(false),
+            Action::OpenPalette => {
+                self.palette = Some(Palette {
+                    focus_mode: self.focus.is_some(),
+                    ..Palette::default()
+                })
+            }
+            Action::Quit => self.should_quit = true,
+        }
+        Ok(())
+    }
+
+    /// Handle a mouse event: click to select or run a footer hint, wheel to move
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> Result<()> {
+        let (column, row) = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::ScrollDown => self.next(),
+            MouseEventKind::ScrollUp => self.previous(),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let targets = &self.click_targets;
+
+                let footer_action = targets
+                    .footer
+                    .iter()
+                    .find(|(area, _)| contains(*area, column, row))
+                    .map(|(_, action)| *action);
+                if let Some(action) = footer_action {
+                    return self.perform(action);
+                }
+
+                let list_index = targets
+                    .list
+                    .filter(|(area, _)| contains(*area, column, row))
+                    .and_then(|(area, item_height)| {
+                        list_index_at(area, item_height, self.list_state.offset(), row)
+                    });
+                let card_index = targets
+                    .kanban
+                    .iter()
+                    .find(|(area, _, _)| contains(*area, column, row))
+                    .and_then(|(area, cards, offset)| {
+                        let position = list_index_at(*area, KANBAN_CARD_HEIGHT, *offset, row)?;
+                        cards.get(position).copied()
+                    });
+                if let Some(index) = list_index.or(card_index) {
+                    self.select(index);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Select a visible task by index (ignored if out of range)
+    fn select(&mut self, index: usize) {
+        if index < self.visible_tasks().len() {
+            self.selected_index = index;
+            self.list_state.select(Some(index));
+        }
+    }
+
+    /// Handle a key press while the command palette is open
+    fn handle_palette_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(palette) = self.palette.as_mut() else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => self.palette = None,
+            KeyCode::Enter => {
+                let action = palette
+                    .matches()
+                    .get(palette.selected)
+                    .map(|(action, _, _)| *action);
+                self.palette = None;
+                if let Some(action) = action {
+                    self.perform(action)?;
+                }
+            }
+            KeyCode::Down => {
+                let count = palette.matches().len();
+                if count > 0 {
+                    palette.selected = (palette.selected + 1) % count;
+                }
+            }
+            KeyCode::Up => {
+                let count = palette.matches().len();
+                if count > 0 {
+                    palette.selected = (palette.selected + count - 1) % count;
+                }
+            }
+            KeyCode::Backspace => {
+                palette.query.pop();
+                palette.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                palette.query.push(c);
+                palette.selected = 0;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Tasks shown in the UI: every session of the focused worktree in focus mode,
+    /// otherwise the latest per worktree, grouped by repository or limited to the
+    /// selected one. The list, Kanban view and mouse hit-testing all use this order.
+    fn visible_tasks(&self) -> Vec<&ClaudeTask> {
+        if self.focus.is_some() {
+            return self
+                .focus_session_ids
+                .iter()
+                .filter_map(|session_id| self.task_manager.get_task(session_id))
+                .collect();
+        }
+
+        let mut tasks = self.task_manager.latest_tasks_by_worktree();
+        match &self.repo_filter {
+            None => {
+                group_by_repo(&mut tasks, &self.git_info_cache);
+                tasks
+            }
+            Some(repo) => tasks
+                .into_iter()
+                .filter(|t| {
+                    self.git_info_cache
+                        .get(&t.worktree_path)
+                        .is_some_and(|info| repo_key(info) == repo)
+                })
+                .collect(),
+        }
+    }
+
+    /// Switch to the next (or previous) repository, cycling through "all repositories"
+    fn cycle_repo(&mut self, forward: bool) {
+        // Focus mode always shows a single worktree
+        if self.focus.is_some() {
+            return;
+        }
+
+        self.cache_all_git_info();
+
+        let mut repos: Vec<String> = self
+            .task_manager
+            .latest_tasks_by_worktree()
+            .iter()
+            .filter_map(|t| self.git_info_cache.get(&t.worktree_path))
+            .map(|info| repo_key(info).to_string())
+            .collect();
+        repos.sort();
+        repos.dedup();
+
+        self.repo_filter = next_repo_filter(&repos, self.repo_filter.as_deref(), forward);
+
+        // Selection indices refer to the filtered list, so start over
+        self.selected_index = 0;
+        let has_tasks = !self.visible_tasks().is_empty();
+        self.list_state.select(has_tasks.then_some(0));
+    }
+
+    /// Move the selection on the Kanban board (no-op in the list view)
+    fn move_kanban(&mut self, step: KanbanMove) {
+        if self.view_mode != ViewMode::Kanban {
+            return;
+        }
+        let columns = kanban_columns(&self.visible_tasks());
+        if let Some(index) = kanban_move(&columns, self.selected_index, step) {
+            self.select(index);
+        }
     }
 
     fn next(&mut self) {
-        let tasks = self.task_manager.latest_tasks_by_worktree();
+        // On the Kanban board j/k move within the current column
+        if self.view_mode == ViewMode::Kanban {
+            return self.move_kanban(KanbanMove::Down);
+        }
+
+        let tasks = self.visible_tasks();
         if tasks.is_empty() {
             return;
         }
@@ -123,7 +657,11 @@ impl App {
     }
 
     fn previous(&mut self) {
-        let tasks = self.task_manager.latest_tasks_by_worktree();
+        if self.view_mode == ViewMode::Kanban {
+            return self.move_kanban(KanbanMove::Up);
+        }
+
+        let tasks = self.visible_tasks();
         if tasks.is_empty() {
             return;
         }
@@ -142,28 +680,59 @@ impl App {
         self.selected_index = i;
     }
 
-    fn refresh(&mut self) -> Result<()> {
+    /// Show a toast notification
+    fn notify(&mut self, level: ToastLevel, message: String) {
+        push_toast(&mut self.toasts, level, message);
+    }
+
+    /// Reload task progress; returns false if reading the progress files failed
+    /// (the failure is shown as a toast and the previous data is kept)
+    fn refresh(&mut self) -> Result<bool> {
         // Refresh Claude Code task progress (only reloads changed files)
-        if let Err(e) = self.task_manager.refresh() {
-            eprintln!(
-                "Warning: Failed to refresh Claude Code task progress: {}",
-                e
-            );
+        let loaded = match self.task_manager.refresh() {
+            Ok(()) => true,
+            Err(e) => {
+                self.notify(
+                    ToastLevel::Warning,
+                    format!("Failed to refresh Claude Code task progress: {}", e),
+                );
+                false
+            }
+        };
+        for warning in self.task_manager.take_warnings() {
+            self.notify(ToastLevel::Warning, warning);
         }
 
+        // New worktrees need git info before they can be grouped or filtered by repository
+        if self.focus.is_none() {
+            self.cache_all_git_info();
+        }
+        self.update_focus_sessions();
+
         // Maintain selection state
-        let tasks = self.task_manager.latest_tasks_by_worktree();
-        if self.selected_index >= tasks.len() {
-            self.selected_index = tasks.len().saturating_sub(1);
+        let task_count = self.visible_tasks().len();
+        if self.selected_index >= task_count {
+            self.selected_index = task_count.saturating_sub(1);
         }
-        if !tasks.is_empty() {
+        if task_count > 0 {
             self.list_state.select(Some(self.selected_index));
         }
 
+        // Git queries are slower than reading task files, so refresh them less often
+        if let Some(focus) = &self.focus {
+            let due = self
+                .focus_refreshed
+                .is_none_or(|at| at.elapsed() >= FOCUS_REFRESH_INTERVAL);
+            if due {
+                self.focus_summary = get_worktree_summary(focus, FOCUS_COMMIT_COUNT);
+                self.focus_refreshed = Some(Instant::now());
+            }
+        }
+
         // Update last refresh time
         self.last_refresh = Instant::now();
 
-        Ok(())
+        Ok(loaded)
     }
 
     /// Check if auto-refresh is needed and perform it
@@ -171,9 +740,36 @@ impl App {
         if self.last_refresh.elapsed() >= self.auto_refresh_interval {
             self.refresh()?;
         }
+        self.toasts
+            .retain(|toast| toast.shown_at.elapsed() < TOAST_DURATION);
         Ok(())
     }
 
+    /// Recompute which sessions belong to the focused worktree
+    fn update_focus_sessions(&mut self) {
+        if let Some(focus) = &self.focus {
+            self.focus_session_ids = self
+                .task_manager
+                .tasks_for_worktree(focus)
+                .iter()
+                .map(|t| t.session_id.clone())
+                .collect();
+        }
+    }
+
+    /// Populate the git info cache for every worktree with tasks, including unfiltered ones
+    fn cache_all_git_info(&mut self) {
+        let worktree_paths: Vec<String> = self
+            .task_manager
+            .latest_tasks_by_worktree()
+            .iter()
+            .map(|t| t.worktree_path.clone())
+            .collect();
+        for path in &worktree_paths {
+            self.get_project_name(path);
+        }
+    }
+
     /// Get project display name for a worktree path, using cache
     fn get_project_name(&mut self, worktree_path: &str) -> String {
         if !self.git_info_cache.contains_key(worktree_path) {
@@ -187,8 +783,67 @@ impl App {
     }
 }
 
+/// Repository key used for grouping (falls back for worktrees without a remote)
+fn repo_key(info: &GitProjectInfo) -> &str {
+    info.repo_name.as_deref().unwrap_or(NO_REMOTE)
+}
+
+/// Repository key for worktrees without a remote
+const NO_REMOTE: &str = "(no remote)";
+
+/// Order tasks by repository, keeping the existing (most recent first) order within each
+fn group_by_repo(tasks: &mut [&ClaudeTask], git_info: &HashMap<String, GitProjectInfo>) {
+    let key = |task: &ClaudeTask| {
+        git_info
+            .get(&task.worktree_path)
+            .map(repo_key)
+            .unwrap_or(NO_REMOTE)
+    };
+    tasks.sort_by(|a, b| key(a).cmp(key(b)));
+}
+
+/// Compute the repository filter after a [ / ] key press
+/// The cycle is: all repositories -> repos[0] -> ... -> repos[n-1] -> all repositories
+fn next_repo_filter(repos: &[String], current: Option<&str>, forward: bool) -> Option<String> {
+    if repos.is_empty() {
+        return None;
+    }
+
+    let position = current.and_then(|c| repos.iter().position(|r| r == c));
+    let next = match (position, forward) {
+        (None, true) => Some(0),
+        (None, false) => Some(repos.len() - 1),
+        (Some(i), true) => (i + 1 < repos.len()).then_some(i + 1),
+        (Some(i), false) => i.checked_sub(1),
+    };
+
+    next.map(|i| repos[i].clone())
+}
+
+/// Leave raw mode and the alternate screen
+///
+/// Errors are ignored: this also runs from the panic hook, where there is
+/// nothing better to do than try.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+}
+
 /// Execute UI command
-pub fn execute() -> Result<()> {
+///
+/// `retention` hides ended/errored sessions older than the given period.
+/// `focus` opens the dashboard for a single worktree.
+pub fn execute(retention: Option<chrono::Duration>, focus: Option<PathBuf>) -> Result<()> {
+    let focus = focus.map(|path| path.display().to_string());
+
+    // Restore the terminal before the panic message is printed,
+    // otherwise it is lost on the alternate screen
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        default_hook(info);
+    }));
+
     // Terminal setup
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -197,23 +852,14 @@ pub fn execute() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run application
-    let app = App::new()?;
-    let res = run_app(&mut terminal, app);
+    let res = App::new(retention, focus).and_then(|app| run_app(&mut terminal, app));
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    restore_terminal();
     terminal.show_cursor()?;
+    let _ = std::panic::take_hook();
 
-    if let Err(err) = res {
-        eprintln!("Error: {:?}", err);
-    }
-
-    Ok(())
+    res
 }
 
 fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
@@ -221,22 +867,32 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: Ap
         terminal.draw(|f| ui(f, &mut app))?;
 
         if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        app.should_quit = true;
-                    }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        app.next();
-                    }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        app.previous();
-                    }
-                    KeyCode::Char('r') => {
-                        app.refresh()?;
-                    }
-                    _ => {}
+            let result = match event::read()? {
+                Event::Key(key) if app.palette.is_some() => app.handle_palette_key(key),
+                Event::Key(key) => {
+                    let action = match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+                        KeyCode::Down | KeyCode::Char('j') => Some(Action::NextTask),
+                        KeyCode::Up | KeyCode::Char('k') => Some(Action::PreviousTask),
+                        KeyCode::Right | KeyCode::Char('l') => Some(Action::NextColumn),
+                        KeyCode::Left | KeyCode::Char('h') => Some(Action::PreviousColumn),
+                        KeyCode::Char('r') => Some(Action::Refresh),
+                        KeyCode::Tab => Some(Action::ToggleView),
+                        KeyCode::Char(']') => Some(Action::NextRepository),
+                        KeyCode::Char('[') => Some(Action::PreviousRepository),
+                        KeyCode::Char(':') => Some(Action::OpenPalette),
+                        _ => None,
+                    };
+                    action.map_or(Ok(()), |action| app.perform(action))
                 }
+                // The palette is keyboard-only; clicks behind it are ignored
+                Event::Mouse(mouse) if app.palette.is_none() => app.handle_mouse(mouse),
+                _ => Ok(()),
+            };
+
+            // Failed actions are reported in the UI instead of closing it
+            if let Err(e) = result {
+                app.notify(ToastLevel::Error, format!("{:#}", e));
             }
         }
 
@@ -255,67 +911,224 @@ fn ui(f: &mut Frame, app: &mut App) {
     let height = f.area().height;
     let mode = DisplayMode::from_height(height);
     let details_height = mode.details_height();
+    let show_focus_panel = app.focus.is_some() && mode != DisplayMode::Minimal;
 
     // Build constraints based on display mode
-    let constraints: Vec<Constraint> = if mode == DisplayMode::Minimal {
-        vec![
-            Constraint::Length(3), // Header
-            Constraint::Min(5),    // Task list (expanded)
-            Constraint::Length(3), // Footer
-        ]
-    } else {
-        vec![
-            Constraint::Length(3),              // Header
-            Constraint::Min(5),                 // Task list
-            Constraint::Length(details_height), // Task details
-            Constraint::Length(3),              // Footer
-        ]
-    };
+    let mut constraints = vec![Constraint::Length(3)]; // Header
+    if show_focus_panel {
+        constraints.push(Constraint::Length(FOCUS_PANEL_HEIGHT)); // Worktree summary
+    }
+    constraints.push(Constraint::Min(5)); // Task list
+    if mode != DisplayMode::Minimal {
+        constraints.push(Constraint::Length(details_height)); // Task details
+    }
+    constraints.push(Constraint::Length(3)); // Footer
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints(constraints)
         .split(f.area());
+    let mut areas = chunks.iter().copied();
 
     // Header
-    let header = Paragraph::new("ccmon - Claude Code Monitor")
+    let header_text = match &app.focus {
+        Some(focus) => format!("ccmon - Claude Code Monitor | Focus: {}", focus),
+        None => format!(
+            "ccmon - Claude Code Monitor | Repository: {} ([ / ] to switch)",
+            app.repo_filter.as_deref().unwrap_or("all repositories")
+        ),
+    };
+    let header = Paragraph::new(header_text)
         .style(
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )
         .block(Block::default().borders(Borders::ALL));
-    f.render_widget(header, chunks[0]);
+    f.render_widget(header, areas.next().unwrap_or_default());
 
-    // Task list
-    render_task_list(f, app, chunks[1], mode);
+    // Worktree summary (focus mode only)
+    if show_focus_panel {
+        render_focus_panel(f, app, areas.next().unwrap_or_default());
+    }
+
+    // Task list or Kanban board
+    let list_area = areas.next().unwrap_or_default();
+    app.click_targets = ClickTargets::default();
+    match app.view_mode {
+        ViewMode::List => render_task_list(f, app, list_area, mode),
+        ViewMode::Kanban => render_kanban(f, app, list_area),
+    }
 
     // Task details (only if not Minimal mode)
     if mode != DisplayMode::Minimal {
-        render_task_details(f, app, chunks[2], mode);
+        render_task_details(f, app, areas.next().unwrap_or_default(), mode);
     }
 
-    // Footer
-    let footer_chunk = if mode == DisplayMode::Minimal {
-        chunks[2]
-    } else {
-        chunks[3]
-    };
-    let active_tasks = app.task_manager.active_tasks().len();
-    let total_tasks = app.task_manager.latest_tasks_by_worktree().len();
-    let footer_text = format!(
-        "Active: {} | Total: {} | Press r to refresh, q to quit",
-        active_tasks, total_tasks
-    );
-    let footer = Paragraph::new(footer_text)
+    // Footer (both counts follow the repository/focus filter)
+    let visible_tasks = app.visible_tasks();
+    let active_tasks = visible_tasks.iter().filter(|t| t.is_active()).count();
+    let total_tasks = visible_tasks.len();
+    let footer_area = areas.next().unwrap_or_default();
+    let prefix = format!("Active: {} | Total: {} | ", active_tasks, total_tasks);
+
+    let mut spans = vec![Span::raw(prefix.clone())];
+    for (index, (hint, _)) in FOOTER_HINTS.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::raw(", "));
+        }
+        spans.push(Span::styled(
+            *hint,
+            Style::default().add_modifier(Modifier::UNDERLINED),
+        ));
+    }
+    let inner = footer_area.inner(Margin::new(1, 1));
+    app.click_targets.footer = footer_hint_targets(inner, prefix.chars().count() as u16);
+
+    let footer = Paragraph::new(Line::from(spans))
         .style(Style::default().fg(Color::Gray))
         .block(Block::default().borders(Borders::ALL));
-    f.render_widget(footer, footer_chunk);
+    f.render_widget(footer, footer_area);
+
+    // Toasts sit above the footer; the command palette overlays everything else
+    render_toasts(f, &app.toasts, footer_area);
+    if let Some(palette) = &app.palette {
+        render_palette(f, palette);
+    }
+}
+
+fn render_toasts(f: &mut Frame, toasts: &[Toast], footer_area: Rect) {
+    let area = f.area();
+    let width = area.width.saturating_sub(4).min(60);
+    let mut bottom = footer_area.y;
+
+    // Newest toast closest to the footer
+    for toast in toasts.iter().rev() {
+        let height = 3;
+        if bottom < area.y + height {
+            break;
+        }
+        bottom -= height;
+        let rect = Rect::new(
+            footer_area.right().saturating_sub(width),
+            bottom,
+            width,
+            height,
+        );
+
+        let color = toast.level.color();
+        let widget = Paragraph::new(toast.message.clone())
+            .style(Style::default().fg(color))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color)),
+            );
+        f.render_widget(Clear, rect);
+        f.render_widget(widget, rect);
+    }
+}
+
+fn render_palette(f: &mut Frame, palette: &Palette) {
+    let matches = palette.matches();
+
+    // Input line + one line per action + borders, centered near the top
+    let area = f.area();
+    let width = area.width.saturating_sub(4).min(50);
+    let height = (PALETTE_ACTIONS.len() as u16 + 4).min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + (area.height.saturating_sub(height)) / 3,
+        width,
+        height,
+    );
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(": ", Style::default().fg(Color::Cyan)),
+            Span::raw(palette.query.clone()),
+        ]),
+        Line::from(""),
+    ];
+    if matches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matching commands",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (index, (_, label, key)) in matches.iter().enumerate() {
+        let style = if index == palette.selected {
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<32}", label), style),
+            Span::styled(format!(" {}", key), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    let widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .title("Commands (Enter to run, Esc to close)"),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(widget, popup);
+}
+
+fn render_focus_panel(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let summary = &app.focus_summary;
+
+    if !summary.is_repository {
+        let panel = Paragraph::new("not a git repository")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::ALL).title("Worktree"));
+        f.render_widget(panel, area);
+        return;
+    }
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Branch: ", Style::default().fg(Color::Cyan)),
+        Span::raw(
+            summary
+                .branch
+                .as_deref()
+                .unwrap_or("(detached)")
+                .to_string(),
+        ),
+        Span::raw("  "),
+        Span::styled("Changes: ", Style::default().fg(Color::Cyan)),
+        match &summary.diff_stat {
+            Some(stat) => Span::styled(stat.clone(), Style::default().fg(Color::Yellow)),
+            None => Span::styled("clean", Style::default().fg(Color::Green)),
+        },
+    ])];
+
+    if summary.recent_commits.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No commits found",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for commit in &summary.recent_commits {
+        lines.push(Line::from(Span::styled(
+            commit.clone(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let panel =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Worktree"));
+    f.render_widget(panel, area);
 }
 
 fn render_task_list(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect, mode: DisplayMode) {
-    let tasks = app.task_manager.latest_tasks_by_worktree();
+    let tasks = app.visible_tasks();
 
     if tasks.is_empty() {
         let empty = Paragraph::new(
@@ -342,7 +1155,7 @@ fn render_task_list(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect, m
         .collect();
 
     // Re-borrow tasks for rendering
-    let tasks = app.task_manager.latest_tasks_by_worktree();
+    let tasks = app.visible_tasks();
     let items: Vec<ListItem> = tasks
         .iter()
         .zip(display_names.iter())
@@ -369,7 +1182,10 @@ fn render_task_list(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect, m
             if mode == DisplayMode::Minimal {
                 let activity_line = Line::from(vec![
                     Span::raw("      "),
-                    Span::styled(&task.last_message, Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        task.last_message.clone(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
                 ]);
                 ListItem::new(vec![main_line, activity_line])
             } else {
@@ -392,10 +1208,13 @@ fn render_task_list(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect, m
         .highlight_symbol(">> ");
 
     f.render_stateful_widget(list, area, &mut app.list_state);
+
+    let item_height = if mode == DisplayMode::Minimal { 2 } else { 1 };
+    app.click_targets.list = Some((area, item_height));
 }
 
 fn render_task_details(f: &mut Frame, app: &App, area: ratatui::layout::Rect, mode: DisplayMode) {
-    let tasks = app.task_manager.latest_tasks_by_worktree();
+    let tasks = app.visible_tasks();
 
     let Some(task) = tasks.get(app.selected_index) else {
         let empty = Paragraph::new("No task selected")
@@ -473,3 +1292,283 @@ fn render_task_details(f: &mut Frame, app: &App, area: ratatui::layout::Rect, mo
         .block(Block::default().borders(Borders::ALL).title("Task Details"));
     f.render_widget(detail, area);
 }
+
+fn render_kanban(f: &mut Frame, app: &mut App, area: ratatui::layout::Rect) {
+    let tasks = app.visible_tasks();
+
+    if tasks.is_empty() {
+        let empty = Paragraph::new(
+            "No Claude Code tasks found\n\nMake sure hooks are initialized with: ccmon init",
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .block(Block::default().borders(Borders::ALL).title("Kanban"));
+        f.render_widget(empty, area);
+        return;
+    }
+
+    // Collect worktree paths first to release the borrow on app
+    let worktree_paths: Vec<String> = tasks.iter().map(|t| t.worktree_path.clone()).collect();
+    drop(tasks);
+
+    // Pre-compute display names (populates cache)
+    let display_names: Vec<String> = worktree_paths
+        .iter()
+        .map(|path| app.get_project_name(path))
+        .collect();
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(area);
+
+    let tasks = app.visible_tasks();
+    let mut kanban_targets = Vec::new();
+    for ((column_area, (title, status)), card_indices) in columns
+        .iter()
+        .zip(KANBAN_COLUMNS.iter())
+        .zip(kanban_columns(&tasks))
+    {
+        let color = status_color(*status);
+
+        let cards: Vec<ListItem> = card_indices
+            .iter()
+            .map(|&index| {
+                let task = tasks[index];
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        display_names[index].clone(),
+                        Style::default().fg(Color::Cyan),
+                    )),
+                    Line::from(Span::styled(
+                        task.duration_string(),
+                        Style::default().fg(Color::DarkGray),
+                    )),
+                    Line::from(Span::raw(task.last_message.clone())),
+                    Line::from(""),
+                ])
+            })
+            .collect();
+
+        let column = List::new(cards)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color))
+                    .title(format!(
+                        "{} {} ({})",
+                        status.emoji(),
+                        title,
+                        card_indices.len()
+                    )),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        // Select the card's position within this column so it is scrolled into view
+        let mut state = ListState::default().with_selected(
+            card_indices
+                .iter()
+                .position(|&index| index == app.selected_index),
+        );
+        f.render_stateful_widget(column, *column_area, &mut state);
+
+        kanban_targets.push((*column_area, card_indices, state.offset()));
+    }
+    app.click_targets.kanban = kanban_targets;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_repo_filter_cycles_through_all() {
+        let repos = vec!["api".to_string(), "web".to_string()];
+
+        assert_eq!(
+            next_repo_filter(&repos, None, true),
+            Some("api".to_string())
+        );
+        assert_eq!(
+            next_repo_filter(&repos, Some("api"), true),
+            Some("web".to_string())
+        );
+        assert_eq!(next_repo_filter(&repos, Some("web"), true), None);
+
+        assert_eq!(
+            next_repo_filter(&repos, None, false),
+            Some("web".to_string())
+        );
+        assert_eq!(next_repo_filter(&repos, Some("api"), false), None);
+
+        // A repository that disappeared restarts the cycle
+        assert_eq!(
+            next_repo_filter(&repos, Some("gone"), true),
+            Some("api".to_string())
+        );
+        assert_eq!(next_repo_filter(&[], None, true), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_contiguous_matches() {
+        assert_eq!(fuzzy_score("", "Quit"), Some(0));
+        assert_eq!(fuzzy_score("QUIT", "Quit"), Some(0));
+        assert_eq!(fuzzy_score("qt", "Quit"), Some(2));
+        assert_eq!(fuzzy_score("tq", "Quit"), None);
+
+        // "refresh" appears contiguously, "repository" does not
+        let refresh = fuzzy_score("refr", "Refresh tasks");
+        let repository = fuzzy_score("refr", "Show previous repository");
+        assert!(refresh < repository || repository.is_none());
+    }
+
+    #[test]
+    fn test_palette_matches_filters_and_ranks() {
+        let palette = Palette {
+            query: "repo".to_string(),
+            ..Palette::default()
+        };
+        let actions: Vec<Action> = palette.matches().iter().map(|(a, _, _)| *a).collect();
+        assert_eq!(
+            actions,
+            vec![Action::NextRepository, Action::PreviousRepository]
+        );
+
+        assert_eq!(Palette::default().matches().len(), PALETTE_ACTIONS.len());
+
+        // Focus mode hides repository switching
+        let palette = Palette {
+            query: "repo".to_string(),
+            focus_mode: true,
+            ..Palette::default()
+        };
+        assert!(palette.matches().is_empty());
+    }
+
+    #[test]
+    fn test_list_index_at_skips_borders_and_applies_offset() {
+        let area = Rect::new(0, 5, 40, 10);
+
+        assert_eq!(list_index_at(area, 1, 0, 5), None); // top border
+        assert_eq!(list_index_at(area, 1, 0, 6), Some(0));
+        assert_eq!(list_index_at(area, 1, 3, 8), Some(5));
+        assert_eq!(list_index_at(area, 2, 0, 9), Some(1));
+        assert_eq!(list_index_at(area, 1, 0, 14), None); // bottom border
+    }
+
+    #[test]
+    fn test_push_toast_folds_repeats_and_caps_count() {
+        let mut toasts = Vec::new();
+
+        push_toast(
+            &mut toasts,
+            ToastLevel::Warning,
+            "refresh failed".to_string(),
+        );
+        push_toast(
+            &mut toasts,
+            ToastLevel::Warning,
+            "refresh failed".to_string(),
+        );
+        assert_eq!(toasts.len(), 1);
+
+        for i in 0..MAX_TOASTS {
+            push_toast(&mut toasts, ToastLevel::Info, format!("message {}", i));
+        }
+        assert_eq!(toasts.len(), MAX_TOASTS);
+        assert_eq!(toasts[0].message, "message 0");
+        assert_eq!(toasts.last().map(|t| t.message.as_str()), Some("message 2"));
+    }
+
+    #[test]
+    fn test_footer_hint_targets_clip_to_inner_area() {
+        // 80-column terminal: footer at x=1..79, inner area x=2..78
+        let inner = Rect::new(2, 28, 76, 1);
+        let prefix_width = "Active: 1 | Total: 1 | ".len() as u16;
+
+        let targets = footer_hint_targets(inner, prefix_width);
+        assert!(targets
+            .iter()
+            .all(|(area, _)| area.x >= inner.x && area.right() <= inner.right()));
+
+        // "q to quit" starts at x=75 and is cut off at the border
+        let quit = targets
+            .iter()
+            .find(|(_, action)| *action == Action::Quit)
+            .map(|(area, _)| *area);
+        assert_eq!(quit, Some(Rect::new(75, 28, 3, 1)));
+        assert!(!targets
+            .iter()
+            .any(|(area, _)| contains(*area, 78, 28) || contains(*area, 79, 28)));
+
+        // Hints starting past the right edge get no region
+        let narrow = Rect::new(2, 28, 30, 1);
+        let targets = footer_hint_targets(narrow, prefix_width);
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].1, Action::OpenPalette);
+    }
+
+    #[test]
+    fn test_group_by_repo_keeps_recency_within_repository() {
+        let task = |path: &str| ClaudeTask {
+            session_id: path.to_string(),
+            start_time: chrono::Utc::now(),
+            last_update: chrono::Utc::now(),
+            status: TaskStatus::InProgress,
+            events: Vec::new(),
+            worktree_path: path.to_string(),
+            last_message: String::new(),
+        };
+        let info = |repo: Option<&str>| GitProjectInfo {
+            repo_name: repo.map(|r| r.to_string()),
+            worktree_name: String::new(),
+        };
+
+        // Most recent first, repositories interleaved
+        let tasks = [task("web-1"), task("api-1"), task("web-2"), task("api-2")];
+        let git_info: HashMap<String, GitProjectInfo> = [
+            ("web-1", info(Some("web"))),
+            ("api-1", info(Some("api"))),
+            ("web-2", info(Some("web"))),
+            ("api-2", info(Some("api"))),
+        ]
+        .into_iter()
+        .map(|(path, info)| (path.to_string(), info))
+        .collect();
+
+        let mut ordered: Vec<&ClaudeTask> = tasks.iter().collect();
+        group_by_repo(&mut ordered, &git_info);
+
+        let paths: Vec<&str> = ordered.iter().map(|t| t.worktree_path.as_str()).collect();
+        assert_eq!(paths, vec!["api-1", "api-2", "web-1", "web-2"]);
+    }
+
+    #[test]
+    fn test_kanban_move_within_and_across_columns() {
+        // In Progress: 0, 3 | Waiting: (empty) | Completed: 1 | Error: 2, 4
+        let columns = vec![vec![0, 3], vec![], vec![1], vec![2, 4]];
+
+        assert_eq!(kanban_move(&columns, 0, KanbanMove::Down), Some(3));
+        assert_eq!(kanban_move(&columns, 3, KanbanMove::Down), Some(0));
+        assert_eq!(kanban_move(&columns, 0, KanbanMove::Up), Some(3));
+
+        // Empty columns are skipped and the row is clamped
+        assert_eq!(kanban_move(&columns, 3, KanbanMove::NextColumn), Some(1));
+        assert_eq!(kanban_move(&columns, 1, KanbanMove::NextColumn), Some(2));
+        assert_eq!(
+            kanban_move(&columns, 4, KanbanMove::PreviousColumn),
+            Some(1)
+        );
+
+        // Board edges stop the move
+        assert_eq!(kanban_move(&columns, 2, KanbanMove::NextColumn), None);
+        assert_eq!(kanban_move(&columns, 0, KanbanMove::PreviousColumn), None);
+
+        // A selection that is not on the board starts at the first card
+        assert_eq!(kanban_move(&columns, 9, KanbanMove::Down), Some(0));
+        assert_eq!(kanban_move(&[vec![], vec![]], 0, KanbanMove::Down), None);
+    }
+}
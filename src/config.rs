@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 /// Set executable permissions on a file (Unix only)
 #[cfg(unix)]
@@ -348,6 +349,12 @@ def main():
         if status is not None:
             event_record["status"] = status
 
+        # Record the target path of file tools (used by 'ccmon show')
+        if hook_event == "PostToolUse":
+            file_path = hook_data.get("tool_input", {}).get("file_path")
+            if file_path:
+                event_record["file_path"] = file_path
+
         # Append to JSONL file with secure permissions (0o600)
         file_exists = progress_file.exists()
         with open(progress_file, "a") as f:
@@ -385,95 +392,281 @@ if __name__ == "__main__":
     main()
 "#;
 
-/// Claude Code hooks ファイルを作成
-pub fn create_claude_hooks(dir: &Path, force: bool) -> Result<Vec<PathBuf>> {
-    let mut created_files = Vec::new();
+/// hooks を作成するディレクトリを検証
+///
+/// hooks はカレントディレクトリに作成する（モノレポのサブプロジェクトで
+/// Claude Code を起動する運用を妨げないよう、git のトップレベルには移動しない）。
+/// `.git` ディレクトリ内（`GIT_DIR` 指定時はその中）で実行された場合のみエラーにする。
+pub fn check_init_dir(dir: &Path) -> Result<()> {
+    ensure_outside_git_dir(dir, Command::new("git"))
+}
 
-    // .claude ディレクトリ作成
-    let claude_dir = dir.join(".claude");
-    if !claude_dir.exists() {
-        fs::create_dir_all(&claude_dir).with_context(|| {
-            format!(
-                "Failed to create .claude directory: {}",
-                claude_dir.display()
-            )
-        })?;
+/// `git` コマンドは呼び出し側が用意する（テストでは継承した `GIT_DIR` を外すため）
+fn ensure_outside_git_dir(dir: &Path, mut git: Command) -> Result<()> {
+    let inside_git_dir = git
+        .args(["rev-parse", "--is-inside-git-dir"])
+        .current_dir(dir)
+        .output()
+        .map(|o| o.status.success() && String::from_utf8_lossy(&o.stdout).trim() == "true")
+        .unwrap_or(false);
+    if inside_git_dir {
+        anyhow::bail!(
+            "Current directory is inside a git directory: {}\n\n\
+             Run this command from the worktree instead.",
+            dir.display()
+        );
     }
+    Ok(())
+}
 
-    // .claude/hooks ディレクトリ作成
-    let hooks_dir = claude_dir.join("hooks");
-    if !hooks_dir.exists() {
-        fs::create_dir_all(&hooks_dir).with_context(|| {
-            format!(
-                "Failed to create .claude/hooks directory: {}",
-                hooks_dir.display()
-            )
-        })?;
+/// init が各ファイルに対して行う操作
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookFileAction {
+    /// 新規作成
+    Create,
+    /// 既存ファイルを上書き
+    Overwrite,
+    /// 既存ファイルを残す
+    Skip,
+    /// 既存ファイルがあり --force が必要（init は失敗する）
+    Conflict,
+}
+
+impl HookFileAction {
+    /// dry-run の一覧に表示するラベル
+    pub fn label(&self) -> &'static str {
+        match self {
+            HookFileAction::Create => "create",
+            HookFileAction::Overwrite => "overwrite",
+            HookFileAction::Skip => "skip",
+            HookFileAction::Conflict => "conflict",
+        }
     }
+}
+
+/// init で書き込む1ファイル分の計画
+#[derive(Debug)]
+pub struct HookFilePlan {
+    pub path: PathBuf,
+    pub action: HookFileAction,
+    content: &'static str,
+    executable: bool,
+    /// エラーメッセージ用のファイル名
+    name: &'static str,
+}
+
+/// Claude Code hooks の作成計画を立てる（ファイルには書き込まない）
+///
+/// `create_claude_hooks` もこの計画をそのまま実行するため、
+/// `init --dry-run` の表示と実際の動作は一致する。
+pub fn plan_claude_hooks(dir: &Path, force: bool) -> Result<Vec<HookFilePlan>> {
+    let claude_dir = dir.join(".claude");
+    let hooks_dir = claude_dir.join("hooks");
+    let home_dir = dirs::home_dir().context("Failed to get home directory")?;
 
-    // 1. .claude/settings.json
-    let settings_path = claude_dir.join("settings.json");
-    if settings_path.exists() && !force {
+    let plan = |path: PathBuf, name, content, executable, existing| HookFilePlan {
+        action: if path.exists() {
+            existing
+        } else {
+            HookFileAction::Create
+        },
+        path,
+        content,
+        executable,
+        name,
+    };
+
+    Ok(vec![
+        // 1. .claude/settings.json（既存の設定は --force なしでは上書きしない）
+        plan(
+            claude_dir.join("settings.json"),
+            "Claude Code settings",
+            CLAUDE_SETTINGS_TEMPLATE,
+            false,
+            if force {
+                HookFileAction::Overwrite
+            } else {
+                HookFileAction::Conflict
+            },
+        ),
+        // 2. .claude/hooks/session-init.sh
+        plan(
+            hooks_dir.join("session-init.sh"),
+            "session-init.sh",
+            SESSION_INIT_HOOK_TEMPLATE,
+            true,
+            HookFileAction::Overwrite,
+        ),
+        // 3. .claude/hooks/track-progress.py
+        plan(
+            hooks_dir.join("track-progress.py"),
+            "track-progress.py",
+            TRACK_PROGRESS_PY_TEMPLATE,
+            true,
+            HookFileAction::Overwrite,
+        ),
+        // 4. ~/.claude/stop-hook-git-check.sh（--force でも上書きしない）
+        plan(
+            home_dir.join(".claude").join("stop-hook-git-check.sh"),
+            "stop-hook-git-check.sh",
+            STOP_HOOK_GIT_CHECK_TEMPLATE,
+            true,
+            HookFileAction::Skip,
+        ),
+    ])
+}
+
+/// Claude Code hooks ファイルを作成
+pub fn create_claude_hooks(dir: &Path, force: bool) -> Result<Vec<PathBuf>> {
+    let plan = plan_claude_hooks(dir, force)?;
+
+    // 何か書き込む前に衝突を確認する
+    if let Some(conflict) = plan
+        .iter()
+        .find(|file| file.action == HookFileAction::Conflict)
+    {
         anyhow::bail!(
             "Claude Code settings file already exists: {}\n\n\
              Use --force to overwrite.",
-            settings_path.display()
+            conflict.path.display()
         );
     }
-    fs::write(&settings_path, CLAUDE_SETTINGS_TEMPLATE).with_context(|| {
-        format!(
-            "Failed to create Claude Code settings: {}",
-            settings_path.display()
-        )
-    })?;
-    created_files.push(settings_path);
-
-    // 2. .claude/hooks/session-init.sh
-    let session_init_path = hooks_dir.join("session-init.sh");
-    fs::write(&session_init_path, SESSION_INIT_HOOK_TEMPLATE).with_context(|| {
-        format!(
-            "Failed to create session-init.sh: {}",
-            session_init_path.display()
-        )
-    })?;
-    set_executable(&session_init_path)?;
-    created_files.push(session_init_path);
-
-    // 3. .claude/hooks/track-progress.py
-    let track_progress_path = hooks_dir.join("track-progress.py");
-    fs::write(&track_progress_path, TRACK_PROGRESS_PY_TEMPLATE).with_context(|| {
-        format!(
-            "Failed to create track-progress.py: {}",
-            track_progress_path.display()
-        )
-    })?;
-    set_executable(&track_progress_path)?;
-    created_files.push(track_progress_path);
-
-    // 4. ~/.claude/stop-hook-git-check.sh
-    let home_dir = dirs::home_dir().context("Failed to get home directory")?;
-    let home_claude_dir = home_dir.join(".claude");
-    if !home_claude_dir.exists() {
-        fs::create_dir_all(&home_claude_dir).with_context(|| {
-            format!(
-                "Failed to create ~/.claude directory: {}",
-                home_claude_dir.display()
-            )
-        })?;
-    }
 
-    // Skip if already exists (do not overwrite even with --force)
-    let stop_hook_path = home_claude_dir.join("stop-hook-git-check.sh");
-    if !stop_hook_path.exists() {
-        fs::write(&stop_hook_path, STOP_HOOK_GIT_CHECK_TEMPLATE).with_context(|| {
-            format!(
-                "Failed to create stop-hook-git-check.sh: {}",
-                stop_hook_path.display()
-            )
-        })?;
-        set_executable(&stop_hook_path)?;
-        created_files.push(stop_hook_path);
+    let mut created_files = Vec::new();
+    for file in plan {
+        if file.action == HookFileAction::Skip {
+            continue;
+        }
+
+        if let Some(parent) = file.path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        fs::write(&file.path, file.content)
+            .with_context(|| format!("Failed to create {}: {}", file.name, file.path.display()))?;
+        if file.executable {
+            set_executable(&file.path)?;
+        }
+        created_files.push(file.path);
     }
 
     Ok(created_files)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// git command that ignores any GIT_DIR/GIT_WORK_TREE inherited from the
+    /// test runner (e.g. when cargo test runs from a git hook)
+    fn clean_git() -> Command {
+        let mut git = Command::new("git");
+        git.env_remove("GIT_DIR").env_remove("GIT_WORK_TREE");
+        git
+    }
+
+    /// Run git in a directory with a clean environment
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        clean_git()
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_init_dir_accepts_subdirectory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        assert!(git(temp_dir.path(), &["init", "-q"]), "git init failed");
+        let sub_dir = temp_dir.path().join("src").join("nested");
+        fs::create_dir_all(&sub_dir)?;
+
+        ensure_outside_git_dir(&sub_dir, clean_git())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_dir_rejects_git_dir() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        assert!(git(temp_dir.path(), &["init", "-q"]), "git init failed");
+
+        assert!(ensure_outside_git_dir(&temp_dir.path().join(".git"), clean_git()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_dir_rejects_explicit_git_dir() -> Result<()> {
+        // Work tree and git directory in separate places, as set up by direnv-style tooling
+        let temp_dir = TempDir::new()?;
+        let work_tree = temp_dir.path().join("work");
+        let git_dir = temp_dir.path().join("store");
+        assert!(
+            git(
+                temp_dir.path(),
+                &[
+                    "init",
+                    "-q",
+                    "--separate-git-dir",
+                    &git_dir.display().to_string(),
+                    &work_tree.display().to_string(),
+                ]
+            ),
+            "git init failed"
+        );
+
+        let git_with_env = || {
+            let mut git = clean_git();
+            git.env("GIT_DIR", &git_dir)
+                .env("GIT_WORK_TREE", &work_tree);
+            git
+        };
+
+        assert!(ensure_outside_git_dir(&git_dir.join("refs"), git_with_env()).is_err());
+        ensure_outside_git_dir(&work_tree, git_with_env())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_plan_claude_hooks_reports_existing_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let actions = |force| -> Result<Vec<HookFileAction>> {
+            // The last entry lives in the real home directory, so only check project files
+            Ok(plan_claude_hooks(temp_dir.path(), force)?
+                .iter()
+                .take(3)
+                .map(|file| file.action)
+                .collect())
+        };
+
+        assert_eq!(actions(false)?, vec![HookFileAction::Create; 3]);
+        assert!(!temp_dir.path().join(".claude").exists());
+
+        fs::create_dir_all(temp_dir.path().join(".claude").join("hooks"))?;
+        fs::write(temp_dir.path().join(".claude").join("settings.json"), "{}")?;
+        fs::write(
+            temp_dir
+                .path()
+                .join(".claude")
+                .join("hooks")
+                .join("session-init.sh"),
+            "",
+        )?;
+
+        assert_eq!(
+            actions(false)?,
+            vec![
+                HookFileAction::Conflict,
+                HookFileAction::Overwrite,
+                HookFileAction::Create
+            ]
+        );
+        assert_eq!(actions(true)?[0], HookFileAction::Overwrite);
+
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@ use clap::{Args, Parser, Subcommand};
 use colored::Colorize;
 use std::fs;
 use std::io::{self, Write};
+use std::path::PathBuf;
+
+use output::{Output, OutputStyle};
 
 #[derive(Parser)]
 #[command(name = "ccmon")]
@@ -21,6 +24,10 @@ struct Cli {
     #[arg(short, long, global = true)]
     quiet: bool,
 
+    /// Use ASCII status symbols instead of emoji
+    #[arg(long, global = true)]
+    no_emoji: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -30,9 +37,13 @@ enum Commands {
     /// Initialize Claude Code hooks
     Init(InitArgs),
     /// Interactive TUI for Claude Code task progress
-    Ui,
+    Ui(UiArgs),
     /// Clear task progress history
     Clear(ClearArgs),
+    /// Bind a Claude Code session to a worktree
+    Bind(BindArgs),
+    /// Show event timeline and tool usage of a Claude Code session
+    Show(ShowArgs),
 }
 
 #[derive(Args)]
@@ -40,6 +51,20 @@ struct InitArgs {
     /// Overwrite existing configuration
     #[arg(short, long)]
     force: bool,
+
+    /// Show which files would be created, overwritten or skipped without writing
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Args)]
+struct UiArgs {
+    /// Focus on a single worktree (path)
+    worktree: Option<PathBuf>,
+
+    /// Hide ended/errored sessions older than this (e.g. 12h, 3d, 1w)
+    #[arg(long, value_parser = commands::claude_task::parse_retention)]
+    retention: Option<chrono::Duration>,
 }
 
 #[derive(Args)]
@@ -47,131 +72,230 @@ struct ClearArgs {
     /// Skip confirmation prompt
     #[arg(short, long)]
     force: bool,
+
+    /// Only clear sessions whose worktree directory no longer exists
+    #[arg(long)]
+    orphaned: bool,
 }
 
-/// Output options for controlling console output verbosity
-#[derive(Clone, Copy)]
-struct OutputOptions {
-    #[allow(dead_code)]
-    verbose: bool,
-    quiet: bool,
+#[derive(Args)]
+struct BindArgs {
+    /// Session ID (or unique prefix)
+    session: String,
+
+    /// Worktree path (defaults to the current directory)
+    path: Option<PathBuf>,
+
+    /// Remove the binding instead of creating it
+    #[arg(long, conflicts_with = "path")]
+    remove: bool,
 }
 
-impl OutputOptions {
-    fn should_print(&self) -> bool {
-        !self.quiet
-    }
+#[derive(Args)]
+struct ShowArgs {
+    /// Session ID (or unique prefix)
+    session: String,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let opts = OutputOptions {
-        verbose: cli.verbose,
-        quiet: cli.quiet,
-    };
+    output::set_emoji_enabled(!cli.no_emoji);
+
+    let mut out = output::create_output(cli.verbose, cli.quiet);
 
     match cli.command {
-        Commands::Init(args) => cmd_init(args, opts),
-        Commands::Ui => cmd_ui(),
-        Commands::Clear(args) => cmd_clear(args, opts),
+        Commands::Init(args) => cmd_init(args, out.as_mut()),
+        Commands::Ui(args) => cmd_ui(args),
+        Commands::Clear(args) => cmd_clear(args, out.as_mut()),
+        Commands::Bind(args) => cmd_bind(args, out.as_mut()),
+        Commands::Show(args) => cmd_show(args, out.as_mut()),
     }
 }
 
 /// init subcommand - creates Claude Code hooks (default behavior)
-fn cmd_init(args: InitArgs, opts: OutputOptions) -> Result<()> {
+fn cmd_init(args: InitArgs, out: &mut dyn Output) -> Result<()> {
     let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+    config::check_init_dir(&current_dir)?;
+
+    out.line(&"Initializing Claude Code hooks...".blue().to_string())?;
+    out.verbose(&format!(
+        "  {} {}",
+        "Target directory:".bright_black(),
+        OutputStyle::path(&current_dir)
+    ))?;
 
-    if opts.should_print() {
-        println!("{}", "Initializing Claude Code hooks...".blue());
+    if args.dry_run {
+        return print_init_plan(&current_dir, args.force, out);
     }
 
     let hook_files = config::create_claude_hooks(&current_dir, args.force)?;
 
-    if opts.should_print() {
-        println!("{}", "Created Claude Code hooks:".green());
-        for file in &hook_files {
-            println!(
-                "  {} {}",
-                "->".bright_black(),
-                file.display().to_string().cyan()
-            );
-        }
+    out.line(&"Created Claude Code hooks:".green().to_string())?;
+    for file in &hook_files {
+        out.line(&format!(
+            "  {} {}",
+            "->".bright_black(),
+            OutputStyle::path(file)
+        ))?;
+    }
+
+    out.line(&format!("\n{}", "Next steps:".blue()))?;
+    out.line(&format!(
+        "  {} Enable hooks for this project: settings are in .claude/settings.json",
+        "1.".bright_black()
+    ))?;
+    out.line(&format!(
+        "  {} Enable globally: cp .claude/settings.json ~/.claude/settings.json",
+        "2.".bright_black()
+    ))?;
+    out.line(&format!(
+        "  {} Customize hooks: edit scripts in .claude/hooks/",
+        "3.".bright_black()
+    ))?;
+
+    out.verbose(&format!(
+        "\n{} {}",
+        "Task progress will be recorded in:".bright_black(),
+        OutputStyle::path(&commands::claude_task::TaskManager::get_progress_dir())
+    ))?;
+
+    Ok(())
+}
+
+/// init --dry-run - lists the planned action for each hook file and a summary
+fn print_init_plan(dir: &std::path::Path, force: bool, out: &mut dyn Output) -> Result<()> {
+    use config::HookFileAction;
 
-        println!("\n{}", "Next steps:".blue());
-        println!(
-            "  {} Enable hooks for this project: settings are in .claude/settings.json",
-            "1.".bright_black()
-        );
-        println!(
-            "  {} Enable globally: cp .claude/settings.json ~/.claude/settings.json",
-            "2.".bright_black()
-        );
-        println!(
-            "  {} Customize hooks: edit scripts in .claude/hooks/",
-            "3.".bright_black()
-        );
+    let plan = config::plan_claude_hooks(dir, force)?;
+
+    out.line(&"Dry run: no files will be written".yellow().to_string())?;
+    for file in &plan {
+        let label = format!("{:10}", file.action.label());
+        let label = match file.action {
+            HookFileAction::Create => label.green(),
+            HookFileAction::Overwrite => label.yellow(),
+            HookFileAction::Skip => label.bright_black(),
+            HookFileAction::Conflict => label.red(),
+        };
+        out.line(&format!("  {} {}", label, OutputStyle::path(&file.path)))?;
+    }
+
+    let count = |action| plan.iter().filter(|file| file.action == action).count();
+    out.line(&format!(
+        "\n{} {} to create, {} to overwrite, {} to skip",
+        "Summary:".blue(),
+        count(HookFileAction::Create),
+        count(HookFileAction::Overwrite),
+        count(HookFileAction::Skip)
+    ))?;
+
+    if count(HookFileAction::Conflict) > 0 {
+        out.line(
+            &OutputStyle::error("Existing settings would block init; use --force to overwrite")
+                .to_string(),
+        )?;
     }
 
     Ok(())
 }
 
+/// Load task progress, reporting skipped files and lines as warnings
+fn load_task_manager(out: &mut dyn Output) -> Result<commands::claude_task::TaskManager> {
+    let mut manager = commands::claude_task::TaskManager::load()?;
+    for warning in manager.take_warnings() {
+        out.warning(&warning)?;
+    }
+    Ok(manager)
+}
+
 /// ui subcommand
-fn cmd_ui() -> Result<()> {
-    commands::ui::execute()
+fn cmd_ui(args: UiArgs) -> Result<()> {
+    let focus = match args.worktree {
+        Some(path) => Some(
+            path.canonicalize()
+                .with_context(|| format!("Worktree path does not exist: {}", path.display()))?,
+        ),
+        None => None,
+    };
+
+    commands::ui::execute(args.retention, focus)
 }
 
 /// clear subcommand - clears task progress history
-fn cmd_clear(args: ClearArgs, opts: OutputOptions) -> Result<()> {
+fn cmd_clear(args: ClearArgs, out: &mut dyn Output) -> Result<()> {
     use commands::claude_task::TaskManager;
 
     let progress_dir = TaskManager::get_progress_dir();
 
     // Check if directory exists
     if !progress_dir.exists() {
-        if opts.should_print() {
-            println!(
-                "{}",
-                output::OutputStyle::success("No task progress files to clear")
-            );
-        }
-        return Ok(());
+        return out.success("No task progress files to clear");
     }
 
+    // Orphaned sessions with their (missing) worktree, shown in the prompt
+    let mut orphans: Vec<(String, String)> = Vec::new();
+
     // Collect .jsonl files
-    let files: Vec<_> = fs::read_dir(&progress_dir)
-        .with_context(|| format!("Failed to read directory: {}", progress_dir.display()))?
-        .filter_map(|e| e.ok())
-        .map(|e| e.path())
-        .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("jsonl"))
-        .collect();
+    let files: Vec<_> = if args.orphaned {
+        let manager = load_task_manager(out)?;
+        for task in manager.orphaned_tasks() {
+            // Session files are named <session_id>.jsonl by the progress hook
+            if progress_dir
+                .join(format!("{}.jsonl", task.session_id))
+                .exists()
+            {
+                orphans.push((task.session_id.clone(), task.worktree_path.clone()));
+            }
+        }
+        orphans
+            .iter()
+            .map(|(session_id, _)| progress_dir.join(format!("{}.jsonl", session_id)))
+            .collect()
+    } else {
+        fs::read_dir(&progress_dir)
+            .with_context(|| format!("Failed to read directory: {}", progress_dir.display()))?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("jsonl"))
+            .collect()
+    };
 
     if files.is_empty() {
-        if opts.should_print() {
-            println!(
-                "{}",
-                output::OutputStyle::success("No task progress files to clear")
-            );
+        // Leftover bindings would still apply to a later session with the same ID
+        if !args.orphaned {
+            TaskManager::clear_bindings()?;
         }
-        return Ok(());
+        return out.success("No task progress files to clear");
     }
 
     // Confirm unless --force
+    // The prompt is interactive, so it bypasses the output sink and is shown even with --quiet
     if !args.force {
-        println!(
-            "Found {} task progress file(s) in {}",
-            files.len(),
-            output::OutputStyle::path(&progress_dir)
-        );
-        print!("Clear all files? [y/N]: ");
+        if args.orphaned {
+            println!(
+                "Found {} orphaned session(s) whose worktree no longer exists:",
+                files.len()
+            );
+            for (session_id, worktree_path) in &orphans {
+                println!("  {} {}", session_id, worktree_path.cyan());
+            }
+            print!("Clear {} orphaned session file(s)? [y/N]: ", files.len());
+        } else {
+            println!(
+                "Found {} task progress file(s) in {}",
+                files.len(),
+                OutputStyle::path(&progress_dir)
+            );
+            print!("Clear all files? [y/N]: ");
+        }
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
         if !input.trim().eq_ignore_ascii_case("y") {
-            println!("{}", "Cancelled".yellow());
-            return Ok(());
+            return out.line(&"Cancelled".yellow().to_string());
         }
     }
 
@@ -179,13 +303,123 @@ fn cmd_clear(args: ClearArgs, opts: OutputOptions) -> Result<()> {
     for file in &files {
         fs::remove_file(file)
             .with_context(|| format!("Failed to delete file: {}", file.display()))?;
+        out.verbose(&format!(
+            "  {} {}",
+            "-".bright_black(),
+            OutputStyle::path(file)
+        ))?;
+    }
+
+    // Bindings of deleted sessions would otherwise linger in bindings.json
+    if args.orphaned {
+        let session_ids: Vec<String> = files
+            .iter()
+            .filter_map(|f| f.file_stem().and_then(|s| s.to_str()))
+            .map(|s| s.to_string())
+            .collect();
+        TaskManager::remove_bindings(&session_ids)?;
+    } else {
+        TaskManager::clear_bindings()?;
+    }
+
+    out.success(&format!("Cleared {} task progress file(s)", files.len()))
+}
+
+/// bind subcommand - binds a Claude Code session to a worktree explicitly
+fn cmd_bind(args: BindArgs, out: &mut dyn Output) -> Result<()> {
+    use commands::claude_task::TaskManager;
+
+    // A binding can outlive its session file, so --remove also accepts
+    // an exact session ID that only exists in the binding file
+    if args.remove && TaskManager::load_bindings()?.contains_key(&args.session) {
+        TaskManager::save_binding(&args.session, None)?;
+        return out.success(&format!("Removed binding for session {}", args.session));
+    }
+
+    let manager = load_task_manager(out)?;
+    let session_id = manager.find_task(&args.session)?.session_id.clone();
+
+    if args.remove {
+        TaskManager::save_binding(&session_id, None)?;
+        return out.success(&format!("Removed binding for session {}", session_id));
+    }
+
+    let path = match args.path {
+        Some(path) => path,
+        None => std::env::current_dir().context("Failed to get current directory")?,
+    };
+    let worktree_path = path
+        .canonicalize()
+        .with_context(|| format!("Worktree path does not exist: {}", path.display()))?;
+
+    TaskManager::save_binding(&session_id, Some(&worktree_path))?;
+
+    out.line(&format!(
+        "{} {}",
+        OutputStyle::success(&format!("Bound session {} to", session_id)),
+        OutputStyle::path(&worktree_path)
+    ))
+}
+
+/// show subcommand - prints the timeline and tool usage of one session
+fn cmd_show(args: ShowArgs, out: &mut dyn Output) -> Result<()> {
+    use commands::claude_task::get_git_project_info;
+
+    let manager = load_task_manager(out)?;
+    let task = manager.find_task(&args.session)?;
+    let project = get_git_project_info(&task.worktree_path);
+
+    let field = |label: &str, value: String| {
+        format!("  {} {}", format!("{:11}", label).bright_black(), value)
+    };
+
+    out.line(&OutputStyle::header(&format!("Session {}", task.session_id)).to_string())?;
+    out.line(&field("Project:", project.display_name()))?;
+    out.line(&field("Directory:", task.worktree_path.cyan().to_string()))?;
+    out.line(&field(
+        "Status:",
+        format!("{} {}", task.status.emoji(), task.status.description()),
+    ))?;
+    out.line(&field(
+        "Started:",
+        task.start_time
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string(),
+    ))?;
+    out.line(&field("Duration:", task.duration_string()))?;
+
+    // Tool usage, most used first
+    let mut usage: Vec<_> = task.tool_usage().into_iter().collect();
+    usage.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    if !usage.is_empty() {
+        out.line(&format!("\n{}", "Tool usage:".blue()))?;
+        for (tool, count) in &usage {
+            out.line(&format!("  {:12} {}", tool, count))?;
+        }
+    }
+
+    let files = task.files_touched();
+    if !files.is_empty() {
+        out.line(&format!("\n{}", "Files touched:".blue()))?;
+        for file in files {
+            out.line(&format!("  {} {}", "->".bright_black(), file.cyan()))?;
+        }
     }
 
-    if opts.should_print() {
-        println!(
-            "{}",
-            output::OutputStyle::success(&format!("Cleared {} task progress file(s)", files.len()))
-        );
+    out.line(&format!("\n{}", "Timeline:".blue()))?;
+    for event in &task.events {
+        out.line(&format!(
+            "  {} {:18} {}",
+            event
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string()
+                .bright_black(),
+            event.event,
+            event.message
+        ))?;
     }
 
     Ok(())
@@ -1,5 +1,147 @@
+use anyhow::Result;
 use colored::*;
+use std::io::{self, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether status glyphs are rendered as emoji (disabled by --no-emoji)
+static EMOJI_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable emoji glyphs for all output
+pub fn set_emoji_enabled(enabled: bool) {
+    EMOJI_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Check whether emoji glyphs are enabled
+pub fn emoji_enabled() -> bool {
+    EMOJI_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Status glyphs used across CLI and TUI output, with ASCII fallbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symbol {
+    Success,
+    Error,
+    Warning,
+    Info,
+    TaskInProgress,
+    TaskStop,
+    TaskSessionEnded,
+    TaskError,
+}
+
+impl Symbol {
+    /// Get the glyph according to the global emoji setting
+    pub fn as_str(self) -> &'static str {
+        self.glyph(emoji_enabled())
+    }
+
+    /// Get the emoji or ASCII glyph
+    pub fn glyph(self, emoji: bool) -> &'static str {
+        match (self, emoji) {
+            (Symbol::Success, true) => "✓",
+            (Symbol::Success, false) => "[OK]",
+            (Symbol::Error, true) => "✗",
+            (Symbol::Error, false) => "[ERROR]",
+            (Symbol::Warning, true) => "⚠",
+            (Symbol::Warning, false) => "[WARN]",
+            (Symbol::Info, true) => "ℹ",
+            (Symbol::Info, false) => "[INFO]",
+            (Symbol::TaskInProgress, true) => "🔵",
+            (Symbol::TaskInProgress, false) => "[RUN]",
+            (Symbol::TaskStop, true) => "🟡",
+            (Symbol::TaskStop, false) => "[STP]",
+            (Symbol::TaskSessionEnded, true) => "⚫",
+            (Symbol::TaskSessionEnded, false) => "[END]",
+            (Symbol::TaskError, true) => "🔴",
+            (Symbol::TaskError, false) => "[ERR]",
+        }
+    }
+}
+
+/// Destination for command output
+///
+/// Commands write through this trait instead of calling `println!` directly,
+/// so quiet mode is handled in one place and output can be captured in tests.
+pub trait Output {
+    /// Write a line of regular output
+    fn line(&mut self, line: &str) -> Result<()>;
+
+    /// Write a line shown only in verbose mode
+    fn verbose(&mut self, line: &str) -> Result<()>;
+
+    /// Write a warning to the diagnostic stream (stderr), separate from regular output
+    fn warning(&mut self, msg: &str) -> Result<()>;
+
+    /// Write a success message
+    fn success(&mut self, msg: &str) -> Result<()> {
+        self.line(&OutputStyle::success(msg).to_string())
+    }
+}
+
+/// Human-readable output: regular lines go to `writer` (stdout in practice),
+/// warnings to `diagnostics` (stderr in practice)
+pub struct HumanOutput<W: Write, E: Write> {
+    writer: W,
+    diagnostics: E,
+    verbose: bool,
+}
+
+impl<W: Write, E: Write> HumanOutput<W, E> {
+    /// Create a human-readable output; verbose lines are written only if `verbose`
+    pub fn new(writer: W, diagnostics: E, verbose: bool) -> Self {
+        Self {
+            writer,
+            diagnostics,
+            verbose,
+        }
+    }
+}
+
+impl<W: Write, E: Write> Output for HumanOutput<W, E> {
+    fn line(&mut self, line: &str) -> Result<()> {
+        writeln!(self.writer, "{}", line)?;
+        Ok(())
+    }
+
+    fn warning(&mut self, msg: &str) -> Result<()> {
+        writeln!(self.diagnostics, "{}", OutputStyle::warning(msg))?;
+        Ok(())
+    }
+
+    fn verbose(&mut self, line: &str) -> Result<()> {
+        if self.verbose {
+            self.line(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Output that discards everything, warnings included (--quiet suppresses all non-error output)
+pub struct QuietOutput;
+
+impl Output for QuietOutput {
+    fn line(&mut self, _line: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn warning(&mut self, _msg: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn verbose(&mut self, _line: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Create the output sink for the global --verbose/--quiet flags
+pub fn create_output(verbose: bool, quiet: bool) -> Box<dyn Output> {
+    if quiet {
+        Box::new(QuietOutput)
+    } else {
+        Box::new(HumanOutput::new(io::stdout(), io::stderr(), verbose))
+    }
+}
 
 /// 出力スタイルユーティリティ
 #[allow(dead_code)]
@@ -8,19 +150,19 @@ pub struct OutputStyle;
 #[allow(dead_code)]
 impl OutputStyle {
     pub fn success(msg: &str) -> ColoredString {
-        format!("✓ {}", msg).green()
+        format!("{} {}", Symbol::Success.as_str(), msg).green()
     }
 
     pub fn error(msg: &str) -> ColoredString {
-        format!("✗ {}", msg).red()
+        format!("{} {}", Symbol::Error.as_str(), msg).red()
     }
 
     pub fn warning(msg: &str) -> ColoredString {
-        format!("⚠ {}", msg).yellow()
+        format!("{} {}", Symbol::Warning.as_str(), msg).yellow()
     }
 
     pub fn info(msg: &str) -> ColoredString {
-        format!("ℹ {}", msg).blue()
+        format!("{} {}", Symbol::Info.as_str(), msg).blue()
     }
 
     pub fn path(path: &Path) -> ColoredString {
@@ -104,6 +246,64 @@ mod tests {
         assert!(result.to_string().contains("/test/path"));
     }
 
+    #[test]
+    fn test_symbol_ascii_fallback() {
+        assert_eq!(Symbol::Success.glyph(true), "✓");
+        assert_eq!(Symbol::TaskInProgress.glyph(true), "🔵");
+
+        // ASCII glyphs must not contain any non-ASCII characters
+        for symbol in [
+            Symbol::Success,
+            Symbol::Error,
+            Symbol::Warning,
+            Symbol::Info,
+            Symbol::TaskInProgress,
+            Symbol::TaskStop,
+            Symbol::TaskSessionEnded,
+            Symbol::TaskError,
+        ] {
+            assert!(symbol.glyph(false).is_ascii());
+        }
+    }
+
+    #[test]
+    fn test_human_output_verbose() -> Result<()> {
+        let mut buffer = Vec::new();
+        {
+            let mut out = HumanOutput::new(&mut buffer, io::sink(), false);
+            out.line("regular")?;
+            out.verbose("details")?;
+        }
+        assert_eq!(String::from_utf8_lossy(&buffer), "regular\n");
+
+        let mut buffer = Vec::new();
+        {
+            let mut out = HumanOutput::new(&mut buffer, io::sink(), true);
+            out.line("regular")?;
+            out.verbose("details")?;
+        }
+        assert_eq!(String::from_utf8_lossy(&buffer), "regular\ndetails\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_human_output_styled_messages() -> Result<()> {
+        let mut buffer = Vec::new();
+        let mut diagnostics = Vec::new();
+        {
+            let mut out = HumanOutput::new(&mut buffer, &mut diagnostics, false);
+            out.success("done")?;
+            out.warning("skipped")?;
+        }
+        assert!(String::from_utf8_lossy(&buffer).contains("done"));
+        // Warnings never mix into regular output
+        assert!(!String::from_utf8_lossy(&buffer).contains("skipped"));
+        assert!(String::from_utf8_lossy(&diagnostics).contains("skipped"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(500), "500 B");
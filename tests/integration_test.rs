@@ -1,3 +1,4 @@
+use std::fs;
 use std::process::Command;
 
 /// ccmon help command displays correctly
@@ -53,3 +54,62 @@ fn test_version() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("ccmon"));
 }
+
+/// clear removes the bindings of the sessions it deletes
+#[test]
+fn test_clear_prunes_bindings() {
+    let home = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let progress_dir = home.path().join(".claude").join("task-progress");
+    fs::create_dir_all(&progress_dir).expect("Failed to create progress dir");
+
+    let worktree = home.path().display().to_string();
+    let missing = home.path().join("removed-worktree").display().to_string();
+    for (session_id, cwd) in [("orphan", &missing), ("alive", &worktree)] {
+        fs::write(
+            progress_dir.join(format!("{}.jsonl", session_id)),
+            format!(
+                r#"{{"timestamp":"2025-12-30T10:00:00Z","session_id":"{}","event":"Stop","tool":null,"status":"stop","message":"Done","cwd":"{}"}}"#,
+                session_id, cwd
+            ),
+        )
+        .expect("Failed to write session file");
+    }
+    let bindings_path = progress_dir.join("bindings.json");
+    fs::write(
+        &bindings_path,
+        format!(r#"{{"orphan":"{}","alive":"{}"}}"#, missing, worktree),
+    )
+    .expect("Failed to write bindings");
+
+    // The binary is run directly: changing HOME for `cargo run` would move CARGO_HOME too
+    let clear = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_ccmon"))
+            .args(args)
+            .env("HOME", home.path())
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    assert!(clear(&["clear", "--orphaned", "--force"]).status.success());
+    let bindings = fs::read_to_string(&bindings_path).expect("Bindings should remain");
+    assert!(!bindings.contains("orphan"));
+    assert!(bindings.contains("alive"));
+
+    assert!(clear(&["clear", "--force"]).status.success());
+    assert!(!bindings_path.exists());
+
+    // Bindings left without any session file: bind --remove takes the exact ID...
+    fs::write(
+        &bindings_path,
+        format!(r#"{{"gone":"{}","stale":"{}"}}"#, worktree, worktree),
+    )
+    .expect("Failed to write bindings");
+    assert!(clear(&["bind", "gone", "--remove"]).status.success());
+    let bindings = fs::read_to_string(&bindings_path).expect("Bindings should remain");
+    assert!(!bindings.contains("gone"));
+    assert!(bindings.contains("stale"));
+
+    // ...and a full clear removes the file even with no session files to delete
+    assert!(clear(&["clear", "--force"]).status.success());
+    assert!(!bindings_path.exists());
+}